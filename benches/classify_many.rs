@@ -0,0 +1,58 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use http_codex::{HttpCode, HttpCodeClass};
+
+/// Generates pseudo-random codes, mostly in the HTTP range
+fn random_codes(count: usize) -> Vec<u32> {
+    let mut state: u32 = 0x2545_f491;
+
+    (0..count)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state % 700
+        })
+        .collect()
+}
+
+fn classify_many(c: &mut Criterion) {
+    let codes = random_codes(1_000_000);
+    let mut group = c.benchmark_group("classify_many");
+
+    group.bench_function("batch", |b| {
+        b.iter(|| black_box(HttpCode::classify_many(black_box(&codes))))
+    });
+
+    group.bench_function("scalar", |b| {
+        b.iter(|| {
+            black_box(
+                black_box(&codes)
+                    .iter()
+                    .map(|code| HttpCodeClass::from(HttpCode::from(*code)))
+                    .collect::<Vec<_>>(),
+            )
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, classify_many);
+criterion_main!(benches);
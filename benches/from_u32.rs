@@ -0,0 +1,139 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use http_codex::HttpCode;
+
+/// The linear match the conversion used to be, kept as a reference
+fn match_lookup(value: u32) -> HttpCode {
+    match value {
+        100 => HttpCode::Continue,
+        101 => HttpCode::SwitchingProtocols,
+        #[cfg(feature = "webdav")]
+        102 => HttpCode::Processing,
+        103 => HttpCode::EarlyHints,
+        200 => HttpCode::Ok,
+        201 => HttpCode::Created,
+        202 => HttpCode::Accepted,
+        203 => HttpCode::NonAuthoritativeInformation,
+        204 => HttpCode::NoContent,
+        205 => HttpCode::ResetContent,
+        206 => HttpCode::PartialContent,
+        #[cfg(feature = "webdav")]
+        207 => HttpCode::MultiStatus,
+        #[cfg(feature = "webdav")]
+        208 => HttpCode::AlreadyReported,
+        226 => HttpCode::ImUsed,
+        300 => HttpCode::MultipleChoices,
+        301 => HttpCode::MovedPermanently,
+        302 => HttpCode::Found,
+        303 => HttpCode::SeeOther,
+        304 => HttpCode::NotModified,
+        307 => HttpCode::TemporaryRedirect,
+        308 => HttpCode::PermanentRedirect,
+        400 => HttpCode::BadRequest,
+        401 => HttpCode::Unauthorized,
+        402 => HttpCode::PaymentRequired,
+        403 => HttpCode::Forbidden,
+        404 => HttpCode::NotFound,
+        405 => HttpCode::MethodNotAllowed,
+        406 => HttpCode::NotAcceptable,
+        407 => HttpCode::ProxyAuthenticationRequired,
+        408 => HttpCode::RequestTimeout,
+        409 => HttpCode::Conflict,
+        410 => HttpCode::Gone,
+        411 => HttpCode::LengthRequired,
+        412 => HttpCode::PreconditionFailed,
+        413 => HttpCode::PayloadTooLarge,
+        414 => HttpCode::UriTooLong,
+        415 => HttpCode::UnsupportedMediaType,
+        416 => HttpCode::RangeNotSatisfiable,
+        417 => HttpCode::ExpectationFailed,
+        418 => HttpCode::ImATeapot,
+        421 => HttpCode::MisdirectedRequest,
+        #[cfg(feature = "webdav")]
+        422 => HttpCode::UnprocessableContent,
+        #[cfg(feature = "webdav")]
+        423 => HttpCode::Locked,
+        #[cfg(feature = "webdav")]
+        424 => HttpCode::FailedDependency,
+        425 => HttpCode::TooEarly,
+        426 => HttpCode::UpgradeRequired,
+        428 => HttpCode::PreconditionRequired,
+        429 => HttpCode::TooManyRequests,
+        431 => HttpCode::RequestHeaderFieldsTooLarge,
+        #[cfg(feature = "nginx")]
+        444 => HttpCode::NoResponse,
+        451 => HttpCode::UnavailableForLegalReasons,
+        #[cfg(feature = "nginx")]
+        499 => HttpCode::ClientClosedRequest,
+        500 => HttpCode::InternalServerError,
+        501 => HttpCode::NotImplemented,
+        502 => HttpCode::BadGateway,
+        503 => HttpCode::ServiceUnavailable,
+        504 => HttpCode::GatewayTimeout,
+        505 => HttpCode::HttpVersionNotSupported,
+        506 => HttpCode::VariantAlsoNegotiates,
+        #[cfg(feature = "webdav")]
+        507 => HttpCode::InsufficientStorage,
+        #[cfg(feature = "webdav")]
+        508 => HttpCode::LoopDetected,
+        510 => HttpCode::NotExtended,
+        511 => HttpCode::NetworkAuthenticationRequired,
+        v => HttpCode::Unknown(v),
+    }
+}
+
+/// Generates pseudo-random codes, mostly in the HTTP range
+fn random_codes(count: usize) -> Vec<u32> {
+    let mut state: u32 = 0x2545_f491;
+
+    (0..count)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state % 700
+        })
+        .collect()
+}
+
+fn from_u32(c: &mut Criterion) {
+    let codes = random_codes(10_000);
+    let mut group = c.benchmark_group("from_u32");
+
+    group.bench_function("lookup_table", |b| {
+        b.iter(|| {
+            for code in &codes {
+                black_box(HttpCode::from(black_box(*code)));
+            }
+        })
+    });
+
+    group.bench_function("match", |b| {
+        b.iter(|| {
+            for code in &codes {
+                black_box(match_lookup(black_box(*code)));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, from_u32);
+criterion_main!(benches);
@@ -0,0 +1,53 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::HttpCode;
+use ::actix_web::http::StatusCode;
+use ::actix_web::ResponseError;
+
+impl ResponseError for HttpCode {
+    /// Returns this code as status, or `500 Internal Server Error` when it has no valid status
+    fn status_code(&self) -> StatusCode {
+        self.status_u16()
+            .and_then(|code| StatusCode::from_u16(code).ok())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::HttpCode;
+    use ::actix_web::http::StatusCode;
+    use ::actix_web::ResponseError;
+
+    #[test]
+    fn response_error() {
+        assert_eq!(HttpCode::NotFound.status_code(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            HttpCode::Forbidden.error_response().status(),
+            StatusCode::FORBIDDEN
+        );
+        assert_eq!(
+            HttpCode::None.status_code(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+        assert_eq!(
+            HttpCode::Unknown(1000).status_code(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
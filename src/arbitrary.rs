@@ -0,0 +1,59 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::HttpCode;
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for HttpCode {
+    /// Generates a standard code most of the time, and otherwise any numeric code or `None`
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        match u.int_in_range(0..=7u8)? {
+            0 => Ok(HttpCode::None),
+            1 => Ok(HttpCode::from(u32::arbitrary(u)?)),
+            _ => Ok(u.choose(HttpCode::KNOWN_CODES)?.1),
+        }
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(5))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::HttpCode;
+    use ::arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn arbitrary_is_deterministic() {
+        let bytes = [1, 0x57, 0x02, 0, 0, 0, 7, 0];
+        let generate = || {
+            let mut u = Unstructured::new(&bytes);
+            [
+                HttpCode::arbitrary(&mut u).unwrap(),
+                HttpCode::arbitrary(&mut u).unwrap(),
+                HttpCode::arbitrary(&mut u).unwrap(),
+            ]
+        };
+
+        assert_eq!(generate(), generate());
+        assert_eq!(
+            generate(),
+            [HttpCode::Unknown(599), HttpCode::None, HttpCode::Continue]
+        );
+    }
+}
@@ -0,0 +1,59 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::HttpCode;
+use ::axum::http::StatusCode;
+use ::axum::response::{IntoResponse, Response};
+
+impl IntoResponse for HttpCode {
+    /// Creates an empty response with this code as status
+    ///
+    /// Codes without a valid status, like `None`, give a `500 Internal Server Error` response.
+    fn into_response(self) -> Response {
+        self.status_u16()
+            .and_then(|code| StatusCode::from_u16(code).ok())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+            .into_response()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::HttpCode;
+    use ::axum::http::StatusCode;
+    use ::axum::response::IntoResponse;
+
+    #[test]
+    fn into_response() {
+        assert_eq!(
+            HttpCode::NotFound.into_response().status(),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            HttpCode::Unknown(599).into_response().status().as_u16(),
+            599
+        );
+        assert_eq!(
+            HttpCode::None.into_response().status(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+        assert_eq!(
+            HttpCode::Unknown(1000).into_response().status(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
@@ -0,0 +1,42 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+/// What a cache should do with the stored validators (like `ETag`) of a resource
+pub enum EtagAction {
+    /// The response carries new validators to store
+    Store,
+
+    /// The stored validators are still valid
+    Confirm,
+
+    /// The response doesn't affect the stored validators
+    Ignore,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+/// How a response should be counted in cache metrics
+pub enum CacheMetric {
+    /// The stored response was validated and reused
+    Hit,
+
+    /// The response had to be fetched from the origin
+    Miss,
+
+    /// The response doesn't count as a cache hit or miss
+    NotApplicable,
+}
@@ -0,0 +1,79 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::http_code::STANDARD_CODES;
+use crate::HttpCode;
+use ::clap::builder::PossibleValue;
+use ::clap::ValueEnum;
+
+/// Converts a variant name to kebab case (`NotFound` becomes `not-found`)
+fn kebab_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            result.push('-');
+        }
+
+        result.push(c.to_ascii_lowercase());
+    }
+
+    result
+}
+
+impl ValueEnum for HttpCode {
+    fn value_variants<'a>() -> &'a [Self] {
+        &STANDARD_CODES
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            HttpCode::None | HttpCode::Unknown(_) => None,
+            _ => Some(
+                PossibleValue::new(kebab_case(self.name())).alias(u32::from(*self).to_string()),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::HttpCode;
+    use ::clap::{value_parser, Arg, Command};
+
+    fn parse(value: &str) -> Option<HttpCode> {
+        Command::new("test")
+            .arg(
+                Arg::new("code")
+                    .long("code")
+                    .value_parser(value_parser!(HttpCode)),
+            )
+            .try_get_matches_from(["test", "--code", value])
+            .ok()
+            .and_then(|matches| matches.get_one::<HttpCode>("code").copied())
+    }
+
+    #[test]
+    fn value_enum() {
+        assert!(matches!(parse("not-found"), Some(HttpCode::NotFound)));
+        assert!(matches!(parse("404"), Some(HttpCode::NotFound)));
+        assert!(matches!(parse("im-a-teapot"), Some(HttpCode::ImATeapot)));
+        assert!(parse("unknown").is_none());
+        assert!(parse("none").is_none());
+        assert!(parse("999").is_none());
+    }
+}
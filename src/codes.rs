@@ -0,0 +1,49 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+//! The constants are derived from [`HttpCode`] and can be used as patterns:
+//!
+//! ```rust
+//! use http_codex::codes;
+//!
+//! let message = match 404 {
+//!     codes::OK => "fine",
+//!     codes::NOT_FOUND => "missing",
+//!     _ => "other",
+//! };
+//! assert_eq!(message, "missing");
+//! ```
+
+use crate::HttpCode;
+
+crate::http_code::code_constants!();
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn constants_match_codes() {
+        assert_eq!(CONSTANTS.len(), HttpCode::KNOWN_COUNT);
+
+        for (&(value, code), &known) in CONSTANTS.iter().zip(HttpCode::KNOWN_CODES) {
+            assert_eq!((value, code), known);
+            assert_eq!(value, u32::from(code));
+            assert_eq!(HttpCode::from(value), code);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The errors that can happen while parsing an HTTP code
+pub enum ParseHttpCodeError {
+    /// The given name doesn't match any known code
+    UnknownName,
+
+    /// The given input is not made of exactly three ASCII digits
+    InvalidDigits,
+
+    /// The given name doesn't match any code class
+    UnknownClass,
+
+    /// The given number is not a whole number that fits a code
+    InvalidNumber,
+}
+
+impl Display for ParseHttpCodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseHttpCodeError::UnknownName => write!(f, "unknown HTTP code name"),
+            ParseHttpCodeError::InvalidDigits => write!(f, "HTTP code must be three ASCII digits"),
+            ParseHttpCodeError::UnknownClass => write!(f, "unknown HTTP code class"),
+            ParseHttpCodeError::InvalidNumber => {
+                write!(f, "HTTP code must be a non-negative whole number")
+            }
+        }
+    }
+}
+
+impl Error for ParseHttpCodeError {}
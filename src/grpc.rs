@@ -0,0 +1,113 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+/// The gRPC status codes, with their numeric value as discriminant
+pub enum GrpcStatus {
+    /// Code 0, not an error
+    Ok = 0,
+
+    /// Code 1, the operation was cancelled, typically by the caller
+    Cancelled = 1,
+
+    /// Code 2, unknown error
+    Unknown = 2,
+
+    /// Code 3, the client specified an invalid argument
+    InvalidArgument = 3,
+
+    /// Code 4, the deadline expired before the operation could complete
+    DeadlineExceeded = 4,
+
+    /// Code 5, some requested entity was not found
+    NotFound = 5,
+
+    /// Code 6, the entity that a client attempted to create already exists
+    AlreadyExists = 6,
+
+    /// Code 7, the caller does not have permission to execute the operation
+    PermissionDenied = 7,
+
+    /// Code 8, some resource has been exhausted, like a per-user quota
+    ResourceExhausted = 8,
+
+    /// Code 9, the system is not in a state required for the operation's execution
+    FailedPrecondition = 9,
+
+    /// Code 10, the operation was aborted, typically due to a concurrency issue
+    Aborted = 10,
+
+    /// Code 11, the operation was attempted past the valid range
+    OutOfRange = 11,
+
+    /// Code 12, the operation is not implemented or not supported
+    Unimplemented = 12,
+
+    /// Code 13, internal error
+    Internal = 13,
+
+    /// Code 14, the service is currently unavailable
+    Unavailable = 14,
+
+    /// Code 15, unrecoverable data loss or corruption
+    DataLoss = 15,
+
+    /// Code 16, the request does not have valid authentication credentials
+    Unauthenticated = 16,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{GrpcStatus, HttpCode};
+
+    #[test]
+    fn code_to_grpc_status() {
+        assert_eq!(HttpCode::Ok.grpc_status(), GrpcStatus::Ok);
+        assert_eq!(HttpCode::NoContent.grpc_status(), GrpcStatus::Ok);
+        assert_eq!(
+            HttpCode::BadRequest.grpc_status(),
+            GrpcStatus::InvalidArgument
+        );
+        assert_eq!(
+            HttpCode::Unauthorized.grpc_status(),
+            GrpcStatus::Unauthenticated
+        );
+        assert_eq!(
+            HttpCode::Forbidden.grpc_status(),
+            GrpcStatus::PermissionDenied
+        );
+        assert_eq!(HttpCode::NotFound.grpc_status(), GrpcStatus::NotFound);
+        assert_eq!(
+            HttpCode::TooManyRequests.grpc_status(),
+            GrpcStatus::ResourceExhausted
+        );
+        assert_eq!(
+            HttpCode::InternalServerError.grpc_status(),
+            GrpcStatus::Internal
+        );
+        assert_eq!(
+            HttpCode::ServiceUnavailable.grpc_status(),
+            GrpcStatus::Unavailable
+        );
+        assert_eq!(
+            HttpCode::GatewayTimeout.grpc_status(),
+            GrpcStatus::DeadlineExceeded
+        );
+        assert_eq!(HttpCode::ImATeapot.grpc_status(), GrpcStatus::Unknown);
+        assert_eq!(HttpCode::None.grpc_status(), GrpcStatus::Unknown);
+    }
+}
@@ -0,0 +1,48 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::HttpCode;
+use ::http::StatusCode;
+
+impl From<HttpCode> for Option<StatusCode> {
+    /// Converts the code to an `http` status, if it has a valid one
+    fn from(value: HttpCode) -> Self {
+        value
+            .status_u16()
+            .and_then(|code| StatusCode::from_u16(code).ok())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::HttpCode;
+    use ::http::StatusCode;
+
+    #[test]
+    fn code_to_status_code() {
+        assert_eq!(
+            Option::<StatusCode>::from(HttpCode::NotFound),
+            Some(StatusCode::NOT_FOUND)
+        );
+        assert_eq!(
+            Option::<StatusCode>::from(HttpCode::Unknown(599)).map(|s| s.as_u16()),
+            Some(599)
+        );
+        assert_eq!(Option::<StatusCode>::from(HttpCode::None), None);
+        assert_eq!(Option::<StatusCode>::from(HttpCode::Unknown(1000)), None);
+    }
+}
@@ -15,408 +15,466 @@
 // along with this program; if not, write to the Free Software Foundation,
 // Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
 
-#[derive(Copy, Clone)]
-/// The HTTP codes with all their descriptions. Thanks to MDN for the documentation
-pub enum HttpCode {
-    /// Code 100
-    ///
-    /// This interim response indicates that the client should continue the request or ignore
-    /// the response if the request is already finished.
-    Continue,
+use crate::{
+    CacheMetric, CodeInfo, EtagAction, GrpcStatus, MethodProblem, ParseHttpCodeError, Severity,
+};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::num::{NonZeroU16, NonZeroU32};
+use std::str::FromStr;
+
+/// Declares [`HttpCode`] from one entry per standard code
+///
+/// The text of each entry is both the documentation of the variant and the value returned by
+/// [`HttpCode::description`], so they can't drift apart. The name in parentheses is the one of
+/// the constant declared in [`codes`](crate::codes).
+macro_rules! http_codes {
+    ($(
+        $(#[cfg($cfg:meta)])?
+        $variant:ident($constant:ident) = $title:literal { $description:literal }
+    )*) => {
+        #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+        /// The HTTP codes with all their descriptions. Thanks to MDN for the documentation
+        ///
+        /// New codes get registered over time, so this enum is non exhaustive and matching on it
+        /// needs a wildcard arm. Use [`HttpCode::classify`] to handle codes added in later
+        /// versions.
+        #[non_exhaustive]
+        pub enum HttpCode {
+            $(
+                #[doc = $title]
+                #[doc = ""]
+                #[doc = $description]
+                $(#[cfg($cfg)])?
+                $variant,
+            )*
+
+            /// No code were given
+            None,
+
+            /// A code was given but is unknown to the library
+            Unknown(u32),
+        }
 
-    /// Code 101
-    ///
-    /// This code is sent in response to an Upgrade request header from the client and
-    /// indicates the protocol the server is switching to.
-    SwitchingProtocols,
+        impl HttpCode {
+            /// Returns the full description of the code, the same one as in the variant's
+            /// documentation
+            ///
+            /// This is useful to explain a code to a user at runtime. Since there is nothing to
+            /// say about them, `None` and `Unknown` codes give an empty string.
+            pub const fn description(&self) -> &'static str {
+                match self {
+                    $($(#[cfg($cfg)])? HttpCode::$variant => $description,)*
+                    HttpCode::None | HttpCode::Unknown(_) => "",
+                }
+            }
+        }
 
-    /// Code 102 (WebDAV)
-    ///
-    /// This code indicates that the server has received and is processing the request,
-    /// but no response is available yet.
-    Processing,
+        /// Declares one constant per variant in the [`codes`](crate::codes) module
+        macro_rules! code_constants {
+            () => {
+                $(
+                    #[doc = concat!("The numeric value of [`HttpCode::", stringify!($variant), "`]")]
+                    $(#[cfg($cfg)])?
+                    pub const $constant: u32 = HttpCode::$variant.code();
+                )*
+
+                #[cfg(test)]
+                /// Every constant with its variant, to check them against the code table
+                const CONSTANTS: &[(u32, HttpCode)] = &[
+                    $($(#[cfg($cfg)])? ($constant, HttpCode::$variant),)*
+                ];
+            };
+        }
 
-    /// Code 103
-    ///
-    /// This status code is primarily intended to be used with the Link header, letting the user
-    /// agent start preloading resources while the server prepares a response or preconnect to
-    /// an origin from which the page will need resources.
-    EarlyHints,
+        pub(crate) use code_constants;
+    };
+}
 
-    /// Code 200
-    ///
-    /// The request succeeded. The result meaning of "success" depends on the HTTP method:
-    ///
-    /// * `GET`: The resource has been fetched and transmitted in the message body.
-    /// * `HEAD`: The representation headers are included in the response without any message body.
-    /// * `PUT` or `POST`: The resource describing the result of the action is transmitted in the message body.
-    /// * `TRACE`: The message body contains the request message as received by the server.
-    Ok,
+http_codes! {
+    Continue(CONTINUE) = "Code 100" {
+        "This interim response indicates that the client should continue the request \
+        or ignore the response if the request is already finished."
+    }
 
-    /// Code 201
-    ///
-    /// The request succeeded, and a new resource was created as a result. This is typically
-    /// the response sent after `POST` requests, or some `PUT` requests.
-    Created,
+    SwitchingProtocols(SWITCHING_PROTOCOLS) = "Code 101" {
+        "This code is sent in response to an Upgrade request header from the client \
+        and indicates the protocol the server is switching to."
+    }
 
-    /// Code 202
-    ///
-    /// The request has been received but not yet acted upon. It is noncommittal, since there is
-    /// no way in HTTP to later send an asynchronous response indicating the outcome of the
-    /// request. It is intended for cases where another process or server handles the request,
-    /// or for batch processing.
-    Accepted,
+    #[cfg(feature = "webdav")]
+    Processing(PROCESSING) = "Code 102 (WebDAV)" {
+        "This code indicates that the server has received and is processing the \
+        request, but no response is available yet."
+    }
 
-    /// Code 203
-    ///
-    /// This response code means the returned metadata is not exactly the same as is available
-    /// from the origin server, but is collected from a local or a third-party copy. This
-    /// is mostly used for mirrors or backups of another resource. Except for that specific case,
-    /// the `200 OK` response is preferred to this status.
-    NonAuthoritativeInformation,
+    EarlyHints(EARLY_HINTS) = "Code 103" {
+        "This status code is primarily intended to be used with the Link header, \
+        letting the user agent start preloading resources while the server prepares \
+        a response or preconnect to an origin from which the page will need \
+        resources."
+    }
 
-    /// Code 204
-    ///
-    /// There is no content to send for this request, but the headers may be useful. The user
-    /// agent may update its cached headers for this resource with the new ones.
-    NoContent,
+    Ok(OK) = "Code 200" {
+        "The request succeeded. The result meaning of \"success\" depends on the HTTP \
+        method:\n\
+        \n\
+        * `GET`: The resource has been fetched and transmitted in the message body.\n\
+        * `HEAD`: The representation headers are included in the response without \
+        any message body.\n\
+        * `PUT` or `POST`: The resource describing the result of the action is \
+        transmitted in the message body.\n\
+        * `TRACE`: The message body contains the request message as received by the \
+        server."
+    }
 
-    /// Code 205
-    ///
-    /// Tells the user agent to reset the document which sent this request.
-    ResetContent,
+    Created(CREATED) = "Code 201" {
+        "The request succeeded, and a new resource was created as a result. This is \
+        typically the response sent after `POST` requests, or some `PUT` requests."
+    }
 
-    /// Code 206
-    ///
-    /// This response code is used when the Range header is sent from the client to request
-    /// only part of a resource.
-    PartialContent,
+    Accepted(ACCEPTED) = "Code 202" {
+        "The request has been received but not yet acted upon. It is noncommittal, \
+        since there is no way in HTTP to later send an asynchronous response \
+        indicating the outcome of the request. It is intended for cases where \
+        another process or server handles the request, or for batch processing."
+    }
 
-    /// Code 207 (WebDAV)
-    ///
-    /// Conveys information about multiple resources, for situations where multiple status codes
-    /// might be appropriate.
-    MultiStatus,
+    NonAuthoritativeInformation(NON_AUTHORITATIVE_INFORMATION) = "Code 203" {
+        "This response code means the returned metadata is not exactly the same as is \
+        available from the origin server, but is collected from a local or a \
+        third-party copy. This is mostly used for mirrors or backups of another \
+        resource. Except for that specific case, the `200 OK` response is preferred \
+        to this status."
+    }
 
-    /// Code 208 (WebDAV)
-    ///
-    /// Used inside a `<dav:propstat>` response element to avoid repeatedly enumerating the
-    /// internal members of multiple bindings to the same collection.
-    AlreadyReported,
+    NoContent(NO_CONTENT) = "Code 204" {
+        "There is no content to send for this request, but the headers may be useful. \
+        The user agent may update its cached headers for this resource with the new \
+        ones."
+    }
 
-    /// Code 226 (HTTP Delta encoding)
-    ///
-    /// The server has fulfilled a `GET` request for the resource, and the response is a
-    /// representation of the result of one or more instance-manipulations applied to the
-    /// current instance.
-    ImUsed,
+    ResetContent(RESET_CONTENT) = "Code 205" {
+        "Tells the user agent to reset the document which sent this request."
+    }
 
-    /// Code 300
-    ///
-    /// The request has more than one possible response. The user agent or user should choose
-    /// one of them. (There is no standardized way of choosing one of the responses, but HTML
-    /// links to the possibilities are recommended so the user can pick.)
-    MultipleChoices,
+    PartialContent(PARTIAL_CONTENT) = "Code 206" {
+        "This response code is used when the Range header is sent from the client to \
+        request only part of a resource."
+    }
 
-    /// Code 301
-    ///
-    /// The URL of the requested resource has been changed permanently. The new URL
-    /// is given in the response.
-    MovedPermanently,
+    #[cfg(feature = "webdav")]
+    MultiStatus(MULTI_STATUS) = "Code 207 (WebDAV)" {
+        "Conveys information about multiple resources, for situations where multiple \
+        status codes might be appropriate."
+    }
 
-    /// Code 302
-    ///
-    /// This response code means that the URI of requested resource has been changed temporarily.
-    /// Further changes in the URI might be made in the future. Therefore, this same URI should
-    /// be used by the client in future requests.
-    Found,
+    #[cfg(feature = "webdav")]
+    AlreadyReported(ALREADY_REPORTED) = "Code 208 (WebDAV)" {
+        "Used inside a `<dav:propstat>` response element to avoid repeatedly \
+        enumerating the internal members of multiple bindings to the same \
+        collection."
+    }
 
-    /// Code 303
-    ///
-    /// The server sent this response to direct the client to get the requested resource at
-    /// another URI with a GET request.
-    SeeOther,
+    ImUsed(IM_USED) = "Code 226 (HTTP Delta encoding)" {
+        "The server has fulfilled a `GET` request for the resource, and the response \
+        is a representation of the result of one or more instance-manipulations \
+        applied to the current instance."
+    }
 
-    /// Code 304
-    ///
-    /// This is used for caching purposes. It tells the client that the response has not been
-    /// modified, so the client can continue to use the same cached version of the response.
-    NotModified,
+    MultipleChoices(MULTIPLE_CHOICES) = "Code 300" {
+        "The request has more than one possible response. The user agent or user \
+        should choose one of them. (There is no standardized way of choosing one of \
+        the responses, but HTML links to the possibilities are recommended so the \
+        user can pick.)"
+    }
 
-    /// Code 307
-    ///
-    /// The server sends this response to direct the client to get the requested resource
-    /// at another URI with the same method that was used in the prior request. This has the
-    /// same semantics as the `302 Found` HTTP response code, with the exception that the user
-    /// agent must not change the HTTP method used: if a `POST` was used in the first request,
-    /// a `POST` must be used in the second request.
-    TemporaryRedirect,
+    MovedPermanently(MOVED_PERMANENTLY) = "Code 301" {
+        "The URL of the requested resource has been changed permanently. The new URL \
+        is given in the response."
+    }
 
-    /// Code 308
-    ///
-    /// This means that the resource is now permanently located at another URI, specified by the
-    /// `Location:` HTTP Response header. This has the same semantics as the `301 Moved Permanently`
-    /// HTTP response code, with the exception that the user agent must not change the HTTP method
-    /// used: if a `POST` was used in the first request, a `POST` must be used in the second request.
-    PermanentRedirect,
+    Found(FOUND) = "Code 302" {
+        "This response code means that the URI of requested resource has been changed \
+        temporarily. Further changes in the URI might be made in the future. \
+        Therefore, this same URI should be used by the client in future requests."
+    }
 
-    /// Code 400
-    ///
-    /// The server cannot or will not process the request due to something that is perceived
-    /// to be a client error (e.g., malformed request syntax, invalid request message framing,
-    /// or deceptive request routing).
-    BadRequest,
+    SeeOther(SEE_OTHER) = "Code 303" {
+        "The server sent this response to direct the client to get the requested \
+        resource at another URI with a GET request."
+    }
 
-    /// Code 401
-    ///
-    /// Although the HTTP standard specifies "unauthorized", semantically this response
-    /// means "unauthenticated". That is, the client must authenticate itself to get
-    /// the requested response.
-    Unauthorized,
+    NotModified(NOT_MODIFIED) = "Code 304" {
+        "This is used for caching purposes. It tells the client that the response has \
+        not been modified, so the client can continue to use the same cached version \
+        of the response."
+    }
 
-    /// Code 402 (Experimental)
-    ///
-    /// This response code is reserved for future use. The initial aim for creating this code was
-    /// using it for digital payment systems, however this status code is used very rarely
-    /// and no standard convention exists.
-    PaymentRequired,
+    TemporaryRedirect(TEMPORARY_REDIRECT) = "Code 307" {
+        "The server sends this response to direct the client to get the requested \
+        resource at another URI with the same method that was used in the prior \
+        request. This has the same semantics as the `302 Found` HTTP response code, \
+        with the exception that the user agent must not change the HTTP method used: \
+        if a `POST` was used in the first request, a `POST` must be used in the \
+        second request."
+    }
 
-    /// Code 403
-    ///
-    /// The client does not have access rights to the content; that is, it is unauthorized, so the
-    /// server is refusing to give the requested resource. Unlike `401 Unauthorized`, the client's
-    /// identity is known to the server.
-    Forbidden,
+    PermanentRedirect(PERMANENT_REDIRECT) = "Code 308" {
+        "This means that the resource is now permanently located at another URI, \
+        specified by the `Location:` HTTP Response header. This has the same \
+        semantics as the `301 Moved Permanently` HTTP response code, with the \
+        exception that the user agent must not change the HTTP method used: if a \
+        `POST` was used in the first request, a `POST` must be used in the second \
+        request."
+    }
 
-    /// Code 404
-    ///
-    /// The server cannot find the requested resource. In the browser, this means the URL is not
-    /// recognized. In an API, this can also mean that the endpoint is valid but the resource
-    /// itself does not exist. Servers may also send this response instead of `403 Forbidden` to
-    /// hide the existence of a resource from an unauthorized client. This response code is
-    /// probably the most well known due to its frequent occurrence on the web.
-    NotFound,
+    BadRequest(BAD_REQUEST) = "Code 400" {
+        "The server cannot or will not process the request due to something that is \
+        perceived to be a client error (e.g., malformed request syntax, invalid \
+        request message framing, or deceptive request routing)."
+    }
 
-    /// Code 405
-    ///
-    /// The request method is known by the server but is not supported by the target resource.
-    /// For example, an API may not allow calling `DELETE` to remove a resource.
-    MethodNotAllowed,
+    Unauthorized(UNAUTHORIZED) = "Code 401" {
+        "Although the HTTP standard specifies \"unauthorized\", semantically this \
+        response means \"unauthenticated\". That is, the client must authenticate \
+        itself to get the requested response."
+    }
 
-    /// Code 406
-    ///
-    /// This response is sent when the web server, after performing server-driven content
-    /// negotiation, doesn't find any content that conforms to the criteria given by the user agent.
-    NotAcceptable,
+    PaymentRequired(PAYMENT_REQUIRED) = "Code 402 (Experimental)" {
+        "This response code is reserved for future use. The initial aim for creating \
+        this code was using it for digital payment systems, however this status code \
+        is used very rarely and no standard convention exists."
+    }
 
-    /// Code 407
-    ///
-    /// This is similar to `401 Unauthorized` but authentication is needed to be done by a proxy.
-    ProxyAuthentificationRequired,
+    Forbidden(FORBIDDEN) = "Code 403" {
+        "The client does not have access rights to the content; that is, it is \
+        unauthorized, so the server is refusing to give the requested resource. \
+        Unlike `401 Unauthorized`, the client's identity is known to the server."
+    }
 
-    /// Code 408
-    ///
-    /// This response is sent on an idle connection by some servers, even without any previous
-    /// request by the client. It means that the server would like to shut down this unused
-    /// connection. This response is used much more since some browsers, like Chrome,
-    /// Firefox 27+, or IE9, use HTTP pre-connection mechanisms to speed up surfing. Also
-    /// note that some servers merely shut down the connection without sending this message.
-    RequestTimeout,
+    NotFound(NOT_FOUND) = "Code 404" {
+        "The server cannot find the requested resource. In the browser, this means \
+        the URL is not recognized. In an API, this can also mean that the endpoint \
+        is valid but the resource itself does not exist. Servers may also send this \
+        response instead of `403 Forbidden` to hide the existence of a resource from \
+        an unauthorized client. This response code is probably the most well known \
+        due to its frequent occurrence on the web."
+    }
 
-    /// Code 409
-    ///
-    /// This response is sent when a request conflicts with the current state of the server.
-    Conflict,
+    MethodNotAllowed(METHOD_NOT_ALLOWED) = "Code 405" {
+        "The request method is known by the server but is not supported by the target \
+        resource. For example, an API may not allow calling `DELETE` to remove a \
+        resource."
+    }
 
-    /// Code 410
-    ///
-    /// This response is sent when the requested content has been permanently deleted from
-    /// server, with no forwarding address. Clients are expected to remove their caches
-    /// and links to the resource. The HTTP specification intends this status code to be used
-    /// for "limited-time, promotional services". APIs should not feel compelled to indicate
-    /// resources that have been deleted with this status code.
-    Gone,
+    NotAcceptable(NOT_ACCEPTABLE) = "Code 406" {
+        "This response is sent when the web server, after performing server-driven \
+        content negotiation, doesn't find any content that conforms to the criteria \
+        given by the user agent."
+    }
 
-    /// Code 411
-    ///
-    /// Server rejected the request because the Content-Length header field is not defined and the
-    /// server requires it.
-    LengthRequired,
+    ProxyAuthenticationRequired(PROXY_AUTHENTICATION_REQUIRED) = "Code 407" {
+        "This is similar to `401 Unauthorized` but authentication is needed to be \
+        done by a proxy."
+    }
 
-    /// Code 412
-    ///
-    /// The client has indicated preconditions in its headers which the server does not meet.
-    PreconditionFailed,
+    RequestTimeout(REQUEST_TIMEOUT) = "Code 408" {
+        "This response is sent on an idle connection by some servers, even without \
+        any previous request by the client. It means that the server would like to \
+        shut down this unused connection. This response is used much more since some \
+        browsers, like Chrome, Firefox 27+, or IE9, use HTTP pre-connection \
+        mechanisms to speed up surfing. Also note that some servers merely shut down \
+        the connection without sending this message."
+    }
 
-    /// Code 413
-    ///
-    /// Request entity is larger than limits defined by server. The server might close the
-    /// connection or return an Retry-After header field.
-    PayloadTooLarge,
+    Conflict(CONFLICT) = "Code 409" {
+        "This response is sent when a request conflicts with the current state of the \
+        server."
+    }
 
-    /// Code 414
-    ///
-    /// The URI requested by the client is longer than the server is willing to interpret.
-    UriTooLong,
+    Gone(GONE) = "Code 410" {
+        "This response is sent when the requested content has been permanently \
+        deleted from server, with no forwarding address. Clients are expected to \
+        remove their caches and links to the resource. The HTTP specification \
+        intends this status code to be used for \"limited-time, promotional \
+        services\". APIs should not feel compelled to indicate resources that have \
+        been deleted with this status code."
+    }
 
-    /// Code 415
-    ///
-    /// The media format of the requested data is not supported by the server, so the server
-    /// is rejecting the request.
-    UnsupportedMediaType,
+    LengthRequired(LENGTH_REQUIRED) = "Code 411" {
+        "Server rejected the request because the Content-Length header field is not \
+        defined and the server requires it."
+    }
 
-    /// Code 416
-    ///
-    /// The range specified by the `Range` header field in the request cannot be fulfilled.
-    /// It's possible that the range is outside the size of the target URI's data.
-    RangeNotSatisfiable,
+    PreconditionFailed(PRECONDITION_FAILED) = "Code 412" {
+        "The client has indicated preconditions in its headers which the server does \
+        not meet."
+    }
 
-    /// Code 417
-    ///
-    /// This response code means the expectation indicated by the Expect request header field
-    /// cannot be met by the server.
-    ExpectationFailed,
+    PayloadTooLarge(PAYLOAD_TOO_LARGE) = "Code 413" {
+        "Request entity is larger than limits defined by server. The server might \
+        close the connection or return an Retry-After header field."
+    }
 
-    /// Code 418
-    ///
-    /// The server refuses the attempt to brew coffee with a teapot.
-    ImATeapot,
+    UriTooLong(URI_TOO_LONG) = "Code 414" {
+        "The URI requested by the client is longer than the server is willing to \
+        interpret."
+    }
 
-    /// Code 421
-    ///
-    /// The request was directed at a server that is not able to produce a response. This can
-    /// be sent by a server that is not configured to produce responses for the combination
-    /// of scheme and authority that are included in the request URI.
-    MisdirectedRequest,
+    UnsupportedMediaType(UNSUPPORTED_MEDIA_TYPE) = "Code 415" {
+        "The media format of the requested data is not supported by the server, so \
+        the server is rejecting the request."
+    }
 
-    /// Code 422 (WebDAV)
-    ///
-    /// The request was well-formed but was unable to be followed due to semantic errors.
-    UnprocessableContent,
+    RangeNotSatisfiable(RANGE_NOT_SATISFIABLE) = "Code 416" {
+        "The range specified by the `Range` header field in the request cannot be \
+        fulfilled. It's possible that the range is outside the size of the target \
+        URI's data."
+    }
 
-    /// Code 423 (WebDAV)
-    ///
-    /// The resource that is being accessed is locked.
-    Locked,
+    ExpectationFailed(EXPECTATION_FAILED) = "Code 417" {
+        "This response code means the expectation indicated by the Expect request \
+        header field cannot be met by the server."
+    }
 
-    /// Code 424 (WebDAV)
-    ///
-    /// The request failed due to failure of a previous request.
-    FailedDependency,
+    ImATeapot(IM_A_TEAPOT) = "Code 418" {
+        "The server refuses the attempt to brew coffee with a teapot."
+    }
 
-    /// Code 425 (Experimental)
-    ///
-    /// Indicates that the server is unwilling to risk processing a request that might be replayed.
-    TooEarly,
+    MisdirectedRequest(MISDIRECTED_REQUEST) = "Code 421" {
+        "The request was directed at a server that is not able to produce a response. \
+        This can be sent by a server that is not configured to produce responses for \
+        the combination of scheme and authority that are included in the request \
+        URI."
+    }
 
-    /// Code 426
-    ///
-    /// The server refuses to perform the request using the current protocol but might be
-    /// willing to do so after the client upgrades to a different protocol. The server sends
-    /// an `Upgrade` header in a 426 response to indicate the required protocol(s).
-    UpgradeRequired,
+    #[cfg(feature = "webdav")]
+    UnprocessableContent(UNPROCESSABLE_CONTENT) = "Code 422 (WebDAV)" {
+        "The request was well-formed but was unable to be followed due to semantic \
+        errors."
+    }
 
-    /// Code 428
-    ///
-    /// The origin server requires the request to be conditional. This response is intended
-    /// to prevent the 'lost update' problem, where a client GETs a resource's state, modifies
-    /// it and PUTs it back to the server, when meanwhile a third party has modified the state
-    /// on the server, leading to a conflict.
-    PreconditionRequired,
+    #[cfg(feature = "webdav")]
+    Locked(LOCKED) = "Code 423 (WebDAV)" {
+        "The resource that is being accessed is locked."
+    }
 
-    /// Code 429
-    ///
-    /// The user has sent too many requests in a given amount of time ("rate limiting").
-    TooManyRequests,
+    #[cfg(feature = "webdav")]
+    FailedDependency(FAILED_DEPENDENCY) = "Code 424 (WebDAV)" {
+        "The request failed due to failure of a previous request."
+    }
 
-    /// Code 431
-    ///
-    /// The server is unwilling to process the request because its header fields are too
-    /// large. The request may be resubmitted after reducing the size of the request header fields.
-    RequestHeaderFieldsTooLarge,
+    TooEarly(TOO_EARLY) = "Code 425 (Experimental)" {
+        "Indicates that the server is unwilling to risk processing a request that \
+        might be replayed."
+    }
 
-    /// Code 451
-    ///
-    /// The user agent requested a resource that cannot legally be provided, such as a web page
-    /// censored by a government.
-    UnavailableForLegalReasons,
+    UpgradeRequired(UPGRADE_REQUIRED) = "Code 426" {
+        "The server refuses to perform the request using the current protocol but \
+        might be willing to do so after the client upgrades to a different protocol. \
+        The server sends an `Upgrade` header in a 426 response to indicate the \
+        required protocol(s)."
+    }
 
-    /// Code 500
-    ///
-    /// The server has encountered a situation it does not know how to handle.
-    InternalServerError,
+    PreconditionRequired(PRECONDITION_REQUIRED) = "Code 428" {
+        "The origin server requires the request to be conditional. This response is \
+        intended to prevent the 'lost update' problem, where a client GETs a \
+        resource's state, modifies it and PUTs it back to the server, when meanwhile \
+        a third party has modified the state on the server, leading to a conflict."
+    }
 
-    /// Code 501
-    ///
-    /// The request method is not supported by the server and cannot be handled. The only
-    /// methods that servers are required to support (and therefore that must not return
-    /// this code) are `GET` and `HEAD`.
-    NotImplemented,
+    TooManyRequests(TOO_MANY_REQUESTS) = "Code 429" {
+        "The user has sent too many requests in a given amount of time (\"rate \
+        limiting\")."
+    }
 
-    /// Code 502
-    ///
-    /// This error response means that the server, while working as a gateway to get a response
-    /// needed to handle the request, got an invalid response.
-    BadGateway,
+    RequestHeaderFieldsTooLarge(REQUEST_HEADER_FIELDS_TOO_LARGE) = "Code 431" {
+        "The server is unwilling to process the request because its header fields are \
+        too large. The request may be resubmitted after reducing the size of the \
+        request header fields."
+    }
 
-    /// Code 503
-    ///
-    /// The server is not ready to handle the request. Common causes are a server that is
-    /// down for maintenance or that is overloaded. Note that together with this response,
-    /// a user-friendly page explaining the problem should be sent. This response should be
-    /// used for temporary conditions and the `Retry-After` HTTP header should, if possible,
-    /// contain the estimated time before the recovery of the service. The webmaster must
-    /// also take care about the caching-related headers that are sent along with this response,
-    /// as these temporary condition responses should usually not be cached.
-    ServiceUnavailable,
+    #[cfg(feature = "nginx")]
+    NoResponse(NO_RESPONSE) = "Code 444 (nginx)" {
+        "Unofficial code used internally by nginx to instruct the server to return no \
+        information to the client and close the connection immediately."
+    }
 
-    /// Code 504
-    ///
-    /// This error response is given when the server is acting as a gateway and cannot get
-    /// a response in time.
-    GatewayTimeout,
+    UnavailableForLegalReasons(UNAVAILABLE_FOR_LEGAL_REASONS) = "Code 451" {
+        "The user agent requested a resource that cannot legally be provided, such as \
+        a web page censored by a government."
+    }
 
-    /// Code 505
-    ///
-    /// The HTTP version used in the request is not supported by the server.
-    HttpVersionNotSupported,
+    #[cfg(feature = "nginx")]
+    ClientClosedRequest(CLIENT_CLOSED_REQUEST) = "Code 499 (nginx)" {
+        "Unofficial code used by nginx to log the case where the client closed the \
+        connection while the server was still processing the request."
+    }
 
-    /// Code 506
-    ///
-    /// The server has an internal configuration error: the chosen variant resource is
-    /// configured to engage in transparent content negotiation itself, and is therefore
-    /// not a proper end point in the negotiation process.
-    VariantAlsoNegotiates,
+    InternalServerError(INTERNAL_SERVER_ERROR) = "Code 500" {
+        "The server has encountered a situation it does not know how to handle."
+    }
 
-    /// Code 507 (WebDAV)
-    ///
-    /// The method could not be performed on the resource because the server is unable to
-    /// store the representation needed to successfully complete the request.
-    InsufficientStorage,
+    NotImplemented(NOT_IMPLEMENTED) = "Code 501" {
+        "The request method is not supported by the server and cannot be handled. The \
+        only methods that servers are required to support (and therefore that must \
+        not return this code) are `GET` and `HEAD`."
+    }
 
-    /// Code 508 (WebDAV)
-    ///
-    /// The server detected an infinite loop while processing the request.
-    LoopDetected,
+    BadGateway(BAD_GATEWAY) = "Code 502" {
+        "This error response means that the server, while working as a gateway to get \
+        a response needed to handle the request, got an invalid response."
+    }
 
-    /// Code 510
-    ///
-    /// Further extensions to the request are required for the server to fulfill it.
-    NotExtended,
+    ServiceUnavailable(SERVICE_UNAVAILABLE) = "Code 503" {
+        "The server is not ready to handle the request. Common causes are a server \
+        that is down for maintenance or that is overloaded. Note that together with \
+        this response, a user-friendly page explaining the problem should be sent. \
+        This response should be used for temporary conditions and the `Retry-After` \
+        HTTP header should, if possible, contain the estimated time before the \
+        recovery of the service. The webmaster must also take care about the \
+        caching-related headers that are sent along with this response, as these \
+        temporary condition responses should usually not be cached."
+    }
 
-    /// Code 511
-    ///
-    /// Indicates that the client needs to authenticate to gain network access.
-    NetworkAuthetificationRequired,
+    GatewayTimeout(GATEWAY_TIMEOUT) = "Code 504" {
+        "This error response is given when the server is acting as a gateway and \
+        cannot get a response in time."
+    }
 
-    /// No code were given
-    None,
+    HttpVersionNotSupported(HTTP_VERSION_NOT_SUPPORTED) = "Code 505" {
+        "The HTTP version used in the request is not supported by the server."
+    }
+
+    VariantAlsoNegotiates(VARIANT_ALSO_NEGOTIATES) = "Code 506" {
+        "The server has an internal configuration error: the chosen variant resource \
+        is configured to engage in transparent content negotiation itself, and is \
+        therefore not a proper end point in the negotiation process."
+    }
+
+    #[cfg(feature = "webdav")]
+    InsufficientStorage(INSUFFICIENT_STORAGE) = "Code 507 (WebDAV)" {
+        "The method could not be performed on the resource because the server is \
+        unable to store the representation needed to successfully complete the \
+        request."
+    }
+
+    #[cfg(feature = "webdav")]
+    LoopDetected(LOOP_DETECTED) = "Code 508 (WebDAV)" {
+        "The server detected an infinite loop while processing the request."
+    }
+
+    NotExtended(NOT_EXTENDED) = "Code 510" {
+        "Further extensions to the request are required for the server to fulfill it."
+    }
 
-    /// A code was given but is unknown to the library
-    Unknown(u32),
+    NetworkAuthenticationRequired(NETWORK_AUTHENTICATION_REQUIRED) = "Code 511" {
+        "Indicates that the client needs to authenticate to gain network access."
+    }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 /// HTTP code classes so comparing code's hundreds is not necessary
 pub enum HttpCodeClass {
     /// Code 1xx
@@ -441,75 +499,253 @@ pub enum HttpCodeClass {
     Unknown,
 }
 
+/// Every standard code with its numeric value, sorted by numeric value
+///
+/// This is the table the numeric conversions are built from.
+pub(crate) const CODE_TABLE: &[(u32, HttpCode)] = &[
+    (100, HttpCode::Continue),
+    (101, HttpCode::SwitchingProtocols),
+    #[cfg(feature = "webdav")]
+    (102, HttpCode::Processing),
+    (103, HttpCode::EarlyHints),
+    (200, HttpCode::Ok),
+    (201, HttpCode::Created),
+    (202, HttpCode::Accepted),
+    (203, HttpCode::NonAuthoritativeInformation),
+    (204, HttpCode::NoContent),
+    (205, HttpCode::ResetContent),
+    (206, HttpCode::PartialContent),
+    #[cfg(feature = "webdav")]
+    (207, HttpCode::MultiStatus),
+    #[cfg(feature = "webdav")]
+    (208, HttpCode::AlreadyReported),
+    (226, HttpCode::ImUsed),
+    (300, HttpCode::MultipleChoices),
+    (301, HttpCode::MovedPermanently),
+    (302, HttpCode::Found),
+    (303, HttpCode::SeeOther),
+    (304, HttpCode::NotModified),
+    (307, HttpCode::TemporaryRedirect),
+    (308, HttpCode::PermanentRedirect),
+    (400, HttpCode::BadRequest),
+    (401, HttpCode::Unauthorized),
+    (402, HttpCode::PaymentRequired),
+    (403, HttpCode::Forbidden),
+    (404, HttpCode::NotFound),
+    (405, HttpCode::MethodNotAllowed),
+    (406, HttpCode::NotAcceptable),
+    (407, HttpCode::ProxyAuthenticationRequired),
+    (408, HttpCode::RequestTimeout),
+    (409, HttpCode::Conflict),
+    (410, HttpCode::Gone),
+    (411, HttpCode::LengthRequired),
+    (412, HttpCode::PreconditionFailed),
+    (413, HttpCode::PayloadTooLarge),
+    (414, HttpCode::UriTooLong),
+    (415, HttpCode::UnsupportedMediaType),
+    (416, HttpCode::RangeNotSatisfiable),
+    (417, HttpCode::ExpectationFailed),
+    (418, HttpCode::ImATeapot),
+    (421, HttpCode::MisdirectedRequest),
+    #[cfg(feature = "webdav")]
+    (422, HttpCode::UnprocessableContent),
+    #[cfg(feature = "webdav")]
+    (423, HttpCode::Locked),
+    #[cfg(feature = "webdav")]
+    (424, HttpCode::FailedDependency),
+    (425, HttpCode::TooEarly),
+    (426, HttpCode::UpgradeRequired),
+    (428, HttpCode::PreconditionRequired),
+    (429, HttpCode::TooManyRequests),
+    (431, HttpCode::RequestHeaderFieldsTooLarge),
+    #[cfg(feature = "nginx")]
+    (444, HttpCode::NoResponse),
+    (451, HttpCode::UnavailableForLegalReasons),
+    #[cfg(feature = "nginx")]
+    (499, HttpCode::ClientClosedRequest),
+    (500, HttpCode::InternalServerError),
+    (501, HttpCode::NotImplemented),
+    (502, HttpCode::BadGateway),
+    (503, HttpCode::ServiceUnavailable),
+    (504, HttpCode::GatewayTimeout),
+    (505, HttpCode::HttpVersionNotSupported),
+    (506, HttpCode::VariantAlsoNegotiates),
+    #[cfg(feature = "webdav")]
+    (507, HttpCode::InsufficientStorage),
+    #[cfg(feature = "webdav")]
+    (508, HttpCode::LoopDetected),
+    (510, HttpCode::NotExtended),
+    (511, HttpCode::NetworkAuthenticationRequired),
+];
+
+/// Fails the build if [`CODE_TABLE`] is not sorted or disagrees with [`HttpCode::code`]
+///
+/// Both conversions between codes and integers rely on the table, so they can't drift apart.
+const _: () = {
+    let mut i = 0;
+
+    while i < CODE_TABLE.len() {
+        assert!(CODE_TABLE[i].1.code() == CODE_TABLE[i].0);
+        assert!(i == 0 || CODE_TABLE[i - 1].0 < CODE_TABLE[i].0);
+        i += 1;
+    }
+};
+
+/// Every standard code, sorted by numeric value
+pub(crate) const STANDARD_CODES: [HttpCode; HttpCode::KNOWN_COUNT] = {
+    let mut codes = [HttpCode::None; HttpCode::KNOWN_COUNT];
+    let mut i = 0;
+
+    while i < CODE_TABLE.len() {
+        codes[i] = CODE_TABLE[i].1;
+        i += 1;
+    }
+
+    codes
+};
+
+/// Number of codes in [`CODE_TABLE`] with the given hundreds digit
+const fn class_len(hundreds: u32) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+
+    while i < CODE_TABLE.len() {
+        if CODE_TABLE[i].0 / 100 == hundreds {
+            len += 1;
+        }
+        i += 1;
+    }
+
+    len
+}
+
+/// Codes of [`CODE_TABLE`] with the given hundreds digit, `N` must be [`class_len`] of it
+const fn class_codes<const N: usize>(hundreds: u32) -> [HttpCode; N] {
+    let mut codes = [HttpCode::None; N];
+    let mut len = 0;
+    let mut i = 0;
+
+    while i < CODE_TABLE.len() {
+        if CODE_TABLE[i].0 / 100 == hundreds {
+            codes[len] = CODE_TABLE[i].1;
+            len += 1;
+        }
+        i += 1;
+    }
+
+    codes
+}
+
+/// The smallest numeric value found in [`CODE_TABLE`]
+const LOOKUP_OFFSET: u32 = CODE_TABLE[0].0;
+
+/// Index of each code in [`CODE_TABLE`] plus one, indexed by numeric value minus [`LOOKUP_OFFSET`]
+///
+/// Numbers without a code have a `0` so the conversion from integers is a single array access.
+const CODE_LOOKUP: [u8; 500] = {
+    let mut lookup = [0; 500];
+    let mut i = 0;
+
+    while i < CODE_TABLE.len() {
+        lookup[(CODE_TABLE[i].0 - LOOKUP_OFFSET) as usize] = i as u8 + 1;
+        i += 1;
+    }
+
+    lookup
+};
+
+/// Class of each numeric value minus [`LOOKUP_OFFSET`], `Unknown` for numbers without a code
+///
+/// Used by [`HttpCode::classify_many`] so classifying a number is a single array access.
+const CLASS_LOOKUP: [HttpCodeClass; 500] = {
+    let mut lookup = [HttpCodeClass::Unknown; 500];
+    let mut i = 0;
+
+    while i < CODE_TABLE.len() {
+        let value = CODE_TABLE[i].0;
+
+        lookup[(value - LOOKUP_OFFSET) as usize] = match value / 100 {
+            1 => HttpCodeClass::Informational,
+            2 => HttpCodeClass::Successful,
+            3 => HttpCodeClass::Redirection,
+            4 => HttpCodeClass::ClientError,
+            _ => HttpCodeClass::ServerError,
+        };
+        i += 1;
+    }
+
+    lookup
+};
+
 impl From<u32> for HttpCode {
     fn from(value: u32) -> Self {
-        match value {
-            100 => HttpCode::Continue,
-            101 => HttpCode::SwitchingProtocols,
-            102 => HttpCode::Processing,
-            103 => HttpCode::EarlyHints,
-            200 => HttpCode::Ok,
-            201 => HttpCode::Created,
-            202 => HttpCode::Accepted,
-            203 => HttpCode::NonAuthoritativeInformation,
-            204 => HttpCode::NoContent,
-            205 => HttpCode::ResetContent,
-            206 => HttpCode::PartialContent,
-            207 => HttpCode::MultiStatus,
-            208 => HttpCode::AlreadyReported,
-            226 => HttpCode::ImUsed,
-            300 => HttpCode::MultipleChoices,
-            301 => HttpCode::MovedPermanently,
-            302 => HttpCode::Found,
-            303 => HttpCode::SeeOther,
-            304 => HttpCode::NotModified,
-            307 => HttpCode::TemporaryRedirect,
-            308 => HttpCode::PermanentRedirect,
-            400 => HttpCode::BadRequest,
-            401 => HttpCode::Unauthorized,
-            402 => HttpCode::PaymentRequired,
-            403 => HttpCode::Forbidden,
-            404 => HttpCode::NotFound,
-            405 => HttpCode::MethodNotAllowed,
-            406 => HttpCode::NotAcceptable,
-            407 => HttpCode::ProxyAuthentificationRequired,
-            408 => HttpCode::RequestTimeout,
-            409 => HttpCode::Conflict,
-            410 => HttpCode::Gone,
-            411 => HttpCode::LengthRequired,
-            412 => HttpCode::PreconditionFailed,
-            413 => HttpCode::PayloadTooLarge,
-            414 => HttpCode::UriTooLong,
-            415 => HttpCode::UnsupportedMediaType,
-            416 => HttpCode::RangeNotSatisfiable,
-            417 => HttpCode::ExpectationFailed,
-            418 => HttpCode::ImATeapot,
-            421 => HttpCode::MisdirectedRequest,
-            422 => HttpCode::UnprocessableContent,
-            423 => HttpCode::Locked,
-            424 => HttpCode::FailedDependency,
-            425 => HttpCode::TooEarly,
-            426 => HttpCode::UpgradeRequired,
-            428 => HttpCode::PreconditionRequired,
-            429 => HttpCode::TooManyRequests,
-            431 => HttpCode::RequestHeaderFieldsTooLarge,
-            451 => HttpCode::UnavailableForLegalReasons,
-            500 => HttpCode::InternalServerError,
-            501 => HttpCode::NotImplemented,
-            502 => HttpCode::BadGateway,
-            503 => HttpCode::ServiceUnavailable,
-            504 => HttpCode::GatewayTimeout,
-            505 => HttpCode::HttpVersionNotSupported,
-            506 => HttpCode::VariantAlsoNegotiates,
-            507 => HttpCode::InsufficientStorage,
-            508 => HttpCode::LoopDetected,
-            510 => HttpCode::NotExtended,
-            511 => HttpCode::NetworkAuthetificationRequired,
-            v => HttpCode::Unknown(v),
+        let index = value
+            .checked_sub(LOOKUP_OFFSET)
+            .and_then(|i| CODE_LOOKUP.get(i as usize))
+            .copied()
+            .unwrap_or(0);
+
+        match index {
+            0 => HttpCode::Unknown(value),
+            i => HttpCode::KNOWN_CODES[i as usize - 1].1,
         }
     }
 }
 
+impl TryFrom<&str> for HttpCode {
+    type Error = ParseHttpCodeError;
+
+    /// Finds a code by its variant name
+    ///
+    /// The comparison ignores case, underscores and hyphens, so `"NotFound"`, `"not_found"` and
+    /// `"not-found"` all give `HttpCode::NotFound`. Numeric strings are not accepted, parse them
+    /// to an integer first.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        STANDARD_CODES
+            .into_iter()
+            .find(|code| name_matches(code.name(), value))
+            .ok_or(ParseHttpCodeError::UnknownName)
+    }
+}
+
+impl TryFrom<&[u8]> for HttpCode {
+    type Error = ParseHttpCodeError;
+
+    /// Parses a code from three ASCII digits, see [`HttpCode::parse_bytes`]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        HttpCode::parse_bytes(value)
+    }
+}
+
+/// Compares a variant name with a user given one, ignoring case, underscores and hyphens
+fn name_matches(name: &str, value: &str) -> bool {
+    let mut value = value.chars().filter(|c| *c != '_' && *c != '-');
+
+    name.chars()
+        .all(|c| value.next().is_some_and(|v| v.eq_ignore_ascii_case(&c)))
+        && value.next().is_none()
+}
+
+/// Returns the field at the given index of an access log line
+///
+/// Fields between brackets or quotes are read as a single field, even if they contain spaces.
+fn log_field(line: &str, index: usize) -> Option<&str> {
+    let mut rest = line.trim_start();
+
+    for _ in 0..index {
+        let end = match rest.as_bytes().first()? {
+            b'"' => rest[1..].find('"')? + 2,
+            b'[' => rest.find(']')? + 1,
+            _ => rest.find(char::is_whitespace)?,
+        };
+
+        rest = rest[end..].trim_start();
+    }
+
+    let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+    Some(&rest[..end]).filter(|field| !field.is_empty())
+}
+
 impl Default for HttpCode {
     fn default() -> Self {
         Self::None
@@ -570,6 +806,18 @@ impl From<isize> for HttpCode {
     }
 }
 
+impl From<NonZeroU32> for HttpCode {
+    fn from(value: NonZeroU32) -> Self {
+        value.get().into()
+    }
+}
+
+impl From<NonZeroU16> for HttpCode {
+    fn from(value: NonZeroU16) -> Self {
+        (value.get() as u32).into()
+    }
+}
+
 impl From<Option<u128>> for HttpCode {
     fn from(value: Option<u128>) -> Self {
         match value {
@@ -661,6 +909,66 @@ impl From<Option<isize>> for HttpCode {
 }
 
 impl HttpCode {
+    /// Former misspelled name of [`HttpCode::ProxyAuthenticationRequired`]
+    ///
+    /// ```compile_fail
+    /// #![deny(deprecated)]
+    /// let _ = http_codex::HttpCode::ProxyAuthentificationRequired;
+    /// ```
+    #[allow(non_upper_case_globals)]
+    #[deprecated(note = "use `HttpCode::ProxyAuthenticationRequired` instead")]
+    pub const ProxyAuthentificationRequired: HttpCode = HttpCode::ProxyAuthenticationRequired;
+
+    /// Former misspelled name of [`HttpCode::NetworkAuthenticationRequired`]
+    ///
+    /// ```compile_fail
+    /// #![deny(deprecated)]
+    /// let _ = http_codex::HttpCode::NetworkAuthetificationRequired;
+    /// ```
+    #[allow(non_upper_case_globals)]
+    #[deprecated(note = "use `HttpCode::NetworkAuthenticationRequired` instead")]
+    pub const NetworkAuthetificationRequired: HttpCode = HttpCode::NetworkAuthenticationRequired;
+
+    /// Every standard code paired with its numeric value, sorted by numeric value
+    ///
+    /// ```
+    /// use http_codex::HttpCode;
+    ///
+    /// assert_eq!(HttpCode::KNOWN_CODES[0], (100, HttpCode::Continue));
+    /// ```
+    pub const KNOWN_CODES: &'static [(u32, HttpCode)] = CODE_TABLE;
+
+    /// Number of standard codes, which depends on the enabled features
+    ///
+    /// ```
+    /// use http_codex::HttpCode;
+    ///
+    /// let buffer = [0u64; HttpCode::KNOWN_COUNT];
+    /// assert_eq!(buffer.len(), HttpCode::all().count());
+    /// ```
+    pub const KNOWN_COUNT: usize = CODE_TABLE.len();
+
+    /// Every standard 1xx code, sorted by numeric value
+    pub const INFORMATIONAL_CODES: &'static [HttpCode] = &class_codes::<{ class_len(1) }>(1);
+
+    /// Every standard 2xx code, sorted by numeric value
+    pub const SUCCESSFUL_CODES: &'static [HttpCode] = &class_codes::<{ class_len(2) }>(2);
+
+    /// Every standard 3xx code, sorted by numeric value
+    pub const REDIRECTION_CODES: &'static [HttpCode] = &class_codes::<{ class_len(3) }>(3);
+
+    /// Every standard 4xx code, sorted by numeric value
+    pub const CLIENT_ERROR_CODES: &'static [HttpCode] = &class_codes::<{ class_len(4) }>(4);
+
+    /// Every standard 5xx code, sorted by numeric value
+    ///
+    /// ```
+    /// use http_codex::HttpCode;
+    ///
+    /// assert!(HttpCode::SERVER_ERROR_CODES.contains(&HttpCode::BadGateway));
+    /// ```
+    pub const SERVER_ERROR_CODES: &'static [HttpCode] = &class_codes::<{ class_len(5) }>(5);
+
     /// Returns the class associated to the code
     pub fn to_class(self) -> HttpCodeClass {
         self.into()
@@ -700,13 +1008,111 @@ impl HttpCode {
     pub fn is_none(&self) -> bool {
         matches!(self.to_class(), HttpCodeClass::None)
     }
-}
 
-impl From<HttpCode> for u32 {
-    fn from(value: HttpCode) -> Self {
-        match value {
+    /// Checks if the code is the outcome of a conditional request's validators
+    ///
+    /// `304 Not Modified` means the validators matched and `412 Precondition Failed` means they
+    /// didn't, so conditional-request clients can branch on this instead of checking both codes.
+    pub fn is_conditional_response(&self) -> bool {
+        matches!(self, HttpCode::NotModified | HttpCode::PreconditionFailed)
+    }
+
+    /// Returns the name of the variant, as written in the code
+    pub(crate) const fn name(&self) -> &'static str {
+        match self {
+            HttpCode::Continue => "Continue",
+            HttpCode::SwitchingProtocols => "SwitchingProtocols",
+            #[cfg(feature = "webdav")]
+            HttpCode::Processing => "Processing",
+            HttpCode::EarlyHints => "EarlyHints",
+            HttpCode::Ok => "Ok",
+            HttpCode::Created => "Created",
+            HttpCode::Accepted => "Accepted",
+            HttpCode::NonAuthoritativeInformation => "NonAuthoritativeInformation",
+            HttpCode::NoContent => "NoContent",
+            HttpCode::ResetContent => "ResetContent",
+            HttpCode::PartialContent => "PartialContent",
+            #[cfg(feature = "webdav")]
+            HttpCode::MultiStatus => "MultiStatus",
+            #[cfg(feature = "webdav")]
+            HttpCode::AlreadyReported => "AlreadyReported",
+            HttpCode::ImUsed => "ImUsed",
+            HttpCode::MultipleChoices => "MultipleChoices",
+            HttpCode::MovedPermanently => "MovedPermanently",
+            HttpCode::Found => "Found",
+            HttpCode::SeeOther => "SeeOther",
+            HttpCode::NotModified => "NotModified",
+            HttpCode::TemporaryRedirect => "TemporaryRedirect",
+            HttpCode::PermanentRedirect => "PermanentRedirect",
+            HttpCode::BadRequest => "BadRequest",
+            HttpCode::Unauthorized => "Unauthorized",
+            HttpCode::PaymentRequired => "PaymentRequired",
+            HttpCode::Forbidden => "Forbidden",
+            HttpCode::NotFound => "NotFound",
+            HttpCode::MethodNotAllowed => "MethodNotAllowed",
+            HttpCode::NotAcceptable => "NotAcceptable",
+            HttpCode::ProxyAuthenticationRequired => "ProxyAuthenticationRequired",
+            HttpCode::RequestTimeout => "RequestTimeout",
+            HttpCode::Conflict => "Conflict",
+            HttpCode::Gone => "Gone",
+            HttpCode::LengthRequired => "LengthRequired",
+            HttpCode::PreconditionFailed => "PreconditionFailed",
+            HttpCode::PayloadTooLarge => "PayloadTooLarge",
+            HttpCode::UriTooLong => "UriTooLong",
+            HttpCode::UnsupportedMediaType => "UnsupportedMediaType",
+            HttpCode::RangeNotSatisfiable => "RangeNotSatisfiable",
+            HttpCode::ExpectationFailed => "ExpectationFailed",
+            HttpCode::ImATeapot => "ImATeapot",
+            HttpCode::MisdirectedRequest => "MisdirectedRequest",
+            #[cfg(feature = "webdav")]
+            HttpCode::UnprocessableContent => "UnprocessableContent",
+            #[cfg(feature = "webdav")]
+            HttpCode::Locked => "Locked",
+            #[cfg(feature = "webdav")]
+            HttpCode::FailedDependency => "FailedDependency",
+            HttpCode::TooEarly => "TooEarly",
+            HttpCode::UpgradeRequired => "UpgradeRequired",
+            HttpCode::PreconditionRequired => "PreconditionRequired",
+            HttpCode::TooManyRequests => "TooManyRequests",
+            HttpCode::RequestHeaderFieldsTooLarge => "RequestHeaderFieldsTooLarge",
+            #[cfg(feature = "nginx")]
+            HttpCode::NoResponse => "NoResponse",
+            HttpCode::UnavailableForLegalReasons => "UnavailableForLegalReasons",
+            #[cfg(feature = "nginx")]
+            HttpCode::ClientClosedRequest => "ClientClosedRequest",
+            HttpCode::InternalServerError => "InternalServerError",
+            HttpCode::NotImplemented => "NotImplemented",
+            HttpCode::BadGateway => "BadGateway",
+            HttpCode::ServiceUnavailable => "ServiceUnavailable",
+            HttpCode::GatewayTimeout => "GatewayTimeout",
+            HttpCode::HttpVersionNotSupported => "HttpVersionNotSupported",
+            HttpCode::VariantAlsoNegotiates => "VariantAlsoNegotiates",
+            #[cfg(feature = "webdav")]
+            HttpCode::InsufficientStorage => "InsufficientStorage",
+            #[cfg(feature = "webdav")]
+            HttpCode::LoopDetected => "LoopDetected",
+            HttpCode::NotExtended => "NotExtended",
+            HttpCode::NetworkAuthenticationRequired => "NetworkAuthenticationRequired",
+            HttpCode::None => "None",
+            HttpCode::Unknown(_) => "Unknown",
+        }
+    }
+
+    /// Returns the numeric value of the code, `0` for `None`
+    ///
+    /// Unlike the `From` conversions, this can be used in constants:
+    ///
+    /// ```
+    /// use http_codex::HttpCode;
+    ///
+    /// const STATUS: u32 = HttpCode::NotFound.code();
+    /// assert_eq!(STATUS, 404);
+    /// ```
+    pub const fn code(&self) -> u32 {
+        match self {
             HttpCode::Continue => 100,
             HttpCode::SwitchingProtocols => 101,
+            #[cfg(feature = "webdav")]
             HttpCode::Processing => 102,
             HttpCode::EarlyHints => 103,
             HttpCode::Ok => 200,
@@ -716,7 +1122,9 @@ impl From<HttpCode> for u32 {
             HttpCode::NoContent => 204,
             HttpCode::ResetContent => 205,
             HttpCode::PartialContent => 206,
+            #[cfg(feature = "webdav")]
             HttpCode::MultiStatus => 207,
+            #[cfg(feature = "webdav")]
             HttpCode::AlreadyReported => 208,
             HttpCode::ImUsed => 226,
             HttpCode::MultipleChoices => 300,
@@ -733,7 +1141,7 @@ impl From<HttpCode> for u32 {
             HttpCode::NotFound => 404,
             HttpCode::MethodNotAllowed => 405,
             HttpCode::NotAcceptable => 406,
-            HttpCode::ProxyAuthentificationRequired => 407,
+            HttpCode::ProxyAuthenticationRequired => 407,
             HttpCode::RequestTimeout => 408,
             HttpCode::Conflict => 409,
             HttpCode::Gone => 410,
@@ -746,15 +1154,22 @@ impl From<HttpCode> for u32 {
             HttpCode::ExpectationFailed => 417,
             HttpCode::ImATeapot => 418,
             HttpCode::MisdirectedRequest => 421,
+            #[cfg(feature = "webdav")]
             HttpCode::UnprocessableContent => 422,
+            #[cfg(feature = "webdav")]
             HttpCode::Locked => 423,
+            #[cfg(feature = "webdav")]
             HttpCode::FailedDependency => 424,
             HttpCode::TooEarly => 425,
             HttpCode::UpgradeRequired => 426,
             HttpCode::PreconditionRequired => 428,
             HttpCode::TooManyRequests => 429,
             HttpCode::RequestHeaderFieldsTooLarge => 431,
+            #[cfg(feature = "nginx")]
+            HttpCode::NoResponse => 444,
             HttpCode::UnavailableForLegalReasons => 451,
+            #[cfg(feature = "nginx")]
+            HttpCode::ClientClosedRequest => 499,
             HttpCode::InternalServerError => 500,
             HttpCode::NotImplemented => 501,
             HttpCode::BadGateway => 502,
@@ -762,60 +1177,1140 @@ impl From<HttpCode> for u32 {
             HttpCode::GatewayTimeout => 504,
             HttpCode::HttpVersionNotSupported => 505,
             HttpCode::VariantAlsoNegotiates => 506,
+            #[cfg(feature = "webdav")]
             HttpCode::InsufficientStorage => 507,
+            #[cfg(feature = "webdav")]
             HttpCode::LoopDetected => 508,
             HttpCode::NotExtended => 510,
-            HttpCode::NetworkAuthetificationRequired => 511,
-            HttpCode::Unknown(v) => v,
+            HttpCode::NetworkAuthenticationRequired => 511,
+            HttpCode::Unknown(v) => *v,
             HttpCode::None => 0,
         }
     }
-}
 
-impl From<HttpCode> for Option<u32> {
-    fn from(value: HttpCode) -> Self {
-        match value {
-            HttpCode::None => None,
-            _ => Some(value.into()),
+    /// Checks if the client should clear the credentials it has stored for this server
+    ///
+    /// Only `401 Unauthorized` means the credentials were rejected, a `403 Forbidden` means they
+    /// were accepted but don't grant access to the resource.
+    pub fn should_clear_credentials(&self) -> bool {
+        matches!(self, HttpCode::Unauthorized)
+    }
+
+    /// Returns a human-readable suggestion about retrying the request
+    ///
+    /// Codes that are not worth retrying give `None`.
+    pub fn retry_suggestion(&self) -> Option<&'static str> {
+        match self {
+            HttpCode::BadGateway | HttpCode::ServiceUnavailable | HttpCode::GatewayTimeout => {
+                Some("Retry after a short delay")
+            }
+            HttpCode::TooManyRequests => Some("You are being rate limited; wait before retrying"),
+            _ => None,
         }
     }
-}
 
-impl From<HttpCode> for HttpCodeClass {
-    fn from(value: HttpCode) -> Self {
-        match value {
-            HttpCode::Continue
-            | HttpCode::SwitchingProtocols
-            | HttpCode::Processing
-            | HttpCode::EarlyHints => HttpCodeClass::Informational,
+    /// Checks if continuing to use the same connection is futile
+    ///
+    /// This is a heuristic for HTTP/2 and HTTP/3 tooling deciding whether to send a `GOAWAY`.
+    /// A `421 Misdirected Request` means the connection reached a server that can't answer for
+    /// this origin and a `505 HTTP Version Not Supported` means the protocol itself is rejected,
+    /// so in both cases any other request sent on this connection would fail the same way.
+    pub fn suggests_connection_teardown(&self) -> bool {
+        matches!(
+            self,
+            HttpCode::MisdirectedRequest | HttpCode::HttpVersionNotSupported
+        )
+    }
 
-            HttpCode::Ok
-            | HttpCode::Created
-            | HttpCode::Accepted
-            | HttpCode::NonAuthoritativeInformation
-            | HttpCode::NoContent
-            | HttpCode::ResetContent
-            | HttpCode::PartialContent
-            | HttpCode::MultiStatus
-            | HttpCode::AlreadyReported
-            | HttpCode::ImUsed => HttpCodeClass::Successful,
+    /// Checks if the error won't go away until the client changes how it builds the request
+    ///
+    /// This is useful to tell "fix your code" errors apart from transient ones like
+    /// `408 Request Timeout`, `429 Too Many Requests` or `503 Service Unavailable` that may
+    /// succeed when retried.
+    pub fn needs_code_change(&self) -> bool {
+        matches!(
+            self,
+            HttpCode::BadRequest
+                | HttpCode::MethodNotAllowed
+                | HttpCode::NotAcceptable
+                | HttpCode::LengthRequired
+                | HttpCode::UriTooLong
+                | HttpCode::UnsupportedMediaType
+                | HttpCode::RequestHeaderFieldsTooLarge
+                | HttpCode::HttpVersionNotSupported
+        )
+    }
 
-            HttpCode::MultipleChoices
-            | HttpCode::MovedPermanently
-            | HttpCode::Found
-            | HttpCode::SeeOther
-            | HttpCode::NotModified
-            | HttpCode::TemporaryRedirect
-            | HttpCode::PermanentRedirect => HttpCodeClass::Redirection,
+    /// Returns the hundreds of the code (`4` for any 4xx code)
+    ///
+    /// This is a lighter alternative to [`HttpCodeClass`] when only the digit is needed, for
+    /// example to group logs. `None` gives `0` and `Unknown` codes give their value divided by
+    /// 100, so `Unknown(599)` gives `5`, `Unknown(42)` gives `0` and `Unknown(700)` gives `7`.
+    pub const fn hundreds(&self) -> u32 {
+        self.code() / 100
+    }
 
-            HttpCode::BadRequest
-            | HttpCode::Unauthorized
-            | HttpCode::PaymentRequired
-            | HttpCode::Forbidden
-            | HttpCode::NotFound
+    /// Checks if the code is the result of content negotiation
+    ///
+    /// Caches storing these responses should honor the `Vary` header since another request with
+    /// different negotiation headers could get another answer.
+    pub fn negotiation_relevant(&self) -> bool {
+        matches!(
+            self,
+            HttpCode::NonAuthoritativeInformation
+                | HttpCode::MultipleChoices
+                | HttpCode::NotAcceptable
+        )
+    }
+
+    /// Returns the specification defining the code, as listed in the IANA status code registry
+    ///
+    /// | Specification | Codes                                                    |
+    /// |---------------|----------------------------------------------------------|
+    /// | RFC 9110      | Every code defined by the core HTTP semantics, and `422` |
+    /// | RFC 2518      | `102`                                                    |
+    /// | RFC 8297      | `103`                                                    |
+    /// | RFC 4918      | `207`, `423`, `424` and `507` (WebDAV)                   |
+    /// | RFC 5842      | `208` and `508` (WebDAV bindings)                        |
+    /// | RFC 3229      | `226`                                                    |
+    /// | RFC 2324      | `418`                                                    |
+    /// | RFC 8470      | `425`                                                    |
+    /// | RFC 6585      | `428`, `429`, `431` and `511`                            |
+    /// | RFC 7725      | `451`                                                    |
+    /// | RFC 2295      | `506`                                                    |
+    /// | RFC 2774      | `510`                                                    |
+    ///
+    /// `None`, `Unknown` and unofficial codes are not defined by any specification and give `None`.
+    pub const fn rfc_reference(&self) -> Option<&'static str> {
+        match self {
+            #[cfg(feature = "webdav")]
+            HttpCode::Processing => Some("RFC 2518"),
+            HttpCode::EarlyHints => Some("RFC 8297"),
+            #[cfg(feature = "webdav")]
+            HttpCode::MultiStatus
+            | HttpCode::Locked
+            | HttpCode::FailedDependency
+            | HttpCode::InsufficientStorage => Some("RFC 4918"),
+            #[cfg(feature = "webdav")]
+            HttpCode::AlreadyReported | HttpCode::LoopDetected => Some("RFC 5842"),
+            HttpCode::ImUsed => Some("RFC 3229"),
+            HttpCode::ImATeapot => Some("RFC 2324"),
+            HttpCode::TooEarly => Some("RFC 8470"),
+            HttpCode::PreconditionRequired
+            | HttpCode::TooManyRequests
+            | HttpCode::RequestHeaderFieldsTooLarge
+            | HttpCode::NetworkAuthenticationRequired => Some("RFC 6585"),
+            HttpCode::UnavailableForLegalReasons => Some("RFC 7725"),
+            HttpCode::VariantAlsoNegotiates => Some("RFC 2295"),
+            HttpCode::NotExtended => Some("RFC 2774"),
+            #[cfg(feature = "nginx")]
+            HttpCode::NoResponse | HttpCode::ClientClosedRequest => None,
+            HttpCode::None | HttpCode::Unknown(_) => None,
+            _ => Some("RFC 9110"),
+        }
+    }
+
+    /// Returns the gRPC status matching the code
+    ///
+    /// The mapping follows the one used by gRPC gateways, like `404 Not Found` giving
+    /// `NOT_FOUND` or `503 Service Unavailable` giving `UNAVAILABLE`. Successful codes give `OK`
+    /// and codes without an equivalent give `UNKNOWN`.
+    pub fn grpc_status(&self) -> GrpcStatus {
+        match self {
+            _ if self.is_successful() => GrpcStatus::Ok,
+            HttpCode::BadRequest => GrpcStatus::InvalidArgument,
+            HttpCode::Unauthorized => GrpcStatus::Unauthenticated,
+            HttpCode::Forbidden => GrpcStatus::PermissionDenied,
+            HttpCode::NotFound => GrpcStatus::NotFound,
+            HttpCode::Conflict => GrpcStatus::Aborted,
+            HttpCode::PreconditionFailed => GrpcStatus::FailedPrecondition,
+            HttpCode::RangeNotSatisfiable => GrpcStatus::OutOfRange,
+            HttpCode::TooManyRequests => GrpcStatus::ResourceExhausted,
+            #[cfg(feature = "nginx")]
+            HttpCode::ClientClosedRequest => GrpcStatus::Cancelled,
+            HttpCode::InternalServerError => GrpcStatus::Internal,
+            HttpCode::NotImplemented => GrpcStatus::Unimplemented,
+            HttpCode::ServiceUnavailable => GrpcStatus::Unavailable,
+            HttpCode::GatewayTimeout => GrpcStatus::DeadlineExceeded,
+            _ => GrpcStatus::Unknown,
+        }
+    }
+
+    /// Returns the `grpc-status` trailer gRPC-Web clients expect for this code
+    ///
+    /// For example, a `404 Not Found` gives `grpc-status: 5` (`NOT_FOUND`).
+    pub fn to_grpc_web_trailer(&self) -> String {
+        format!("grpc-status: {}", self.grpc_status() as u32)
+    }
+
+    /// Checks if the code is one of the named variants, `None` and `Unknown` codes aren't
+    ///
+    /// Codes enabled by features, like the nginx ones, are named variants too.
+    pub const fn is_standard(&self) -> bool {
+        !matches!(self, HttpCode::None | HttpCode::Unknown(_))
+    }
+
+    /// Checks if the code often precedes the server closing the connection
+    ///
+    /// This is only advisory: servers commonly close the connection after a framing error, a
+    /// timeout or a request too large to be read, but nothing forces them to. Use it as a hint
+    /// to avoid reusing the connection, the `Connection` header has the final say.
+    pub fn should_close_connection(&self) -> bool {
+        matches!(
+            self,
+            HttpCode::BadRequest
+                | HttpCode::RequestTimeout
+                | HttpCode::PayloadTooLarge
+                | HttpCode::UriTooLong
+                | HttpCode::MisdirectedRequest
+                | HttpCode::RequestHeaderFieldsTooLarge
+        )
+    }
+
+    /// Returns the reason phrase of the code, like `Not Found`
+    ///
+    /// `None` and `Unknown` codes have no reason phrase and give an empty string.
+    pub const fn reason_phrase(&self) -> &'static str {
+        match self {
+            HttpCode::Continue => "Continue",
+            HttpCode::SwitchingProtocols => "Switching Protocols",
+            #[cfg(feature = "webdav")]
+            HttpCode::Processing => "Processing",
+            HttpCode::EarlyHints => "Early Hints",
+            HttpCode::Ok => "OK",
+            HttpCode::Created => "Created",
+            HttpCode::Accepted => "Accepted",
+            HttpCode::NonAuthoritativeInformation => "Non-Authoritative Information",
+            HttpCode::NoContent => "No Content",
+            HttpCode::ResetContent => "Reset Content",
+            HttpCode::PartialContent => "Partial Content",
+            #[cfg(feature = "webdav")]
+            HttpCode::MultiStatus => "Multi-Status",
+            #[cfg(feature = "webdav")]
+            HttpCode::AlreadyReported => "Already Reported",
+            HttpCode::ImUsed => "IM Used",
+            HttpCode::MultipleChoices => "Multiple Choices",
+            HttpCode::MovedPermanently => "Moved Permanently",
+            HttpCode::Found => "Found",
+            HttpCode::SeeOther => "See Other",
+            HttpCode::NotModified => "Not Modified",
+            HttpCode::TemporaryRedirect => "Temporary Redirect",
+            HttpCode::PermanentRedirect => "Permanent Redirect",
+            HttpCode::BadRequest => "Bad Request",
+            HttpCode::Unauthorized => "Unauthorized",
+            HttpCode::PaymentRequired => "Payment Required",
+            HttpCode::Forbidden => "Forbidden",
+            HttpCode::NotFound => "Not Found",
+            HttpCode::MethodNotAllowed => "Method Not Allowed",
+            HttpCode::NotAcceptable => "Not Acceptable",
+            HttpCode::ProxyAuthenticationRequired => "Proxy Authentication Required",
+            HttpCode::RequestTimeout => "Request Timeout",
+            HttpCode::Conflict => "Conflict",
+            HttpCode::Gone => "Gone",
+            HttpCode::LengthRequired => "Length Required",
+            HttpCode::PreconditionFailed => "Precondition Failed",
+            HttpCode::PayloadTooLarge => "Payload Too Large",
+            HttpCode::UriTooLong => "URI Too Long",
+            HttpCode::UnsupportedMediaType => "Unsupported Media Type",
+            HttpCode::RangeNotSatisfiable => "Range Not Satisfiable",
+            HttpCode::ExpectationFailed => "Expectation Failed",
+            HttpCode::ImATeapot => "I'm a teapot",
+            HttpCode::MisdirectedRequest => "Misdirected Request",
+            #[cfg(feature = "webdav")]
+            HttpCode::UnprocessableContent => "Unprocessable Content",
+            #[cfg(feature = "webdav")]
+            HttpCode::Locked => "Locked",
+            #[cfg(feature = "webdav")]
+            HttpCode::FailedDependency => "Failed Dependency",
+            HttpCode::TooEarly => "Too Early",
+            HttpCode::UpgradeRequired => "Upgrade Required",
+            HttpCode::PreconditionRequired => "Precondition Required",
+            HttpCode::TooManyRequests => "Too Many Requests",
+            HttpCode::RequestHeaderFieldsTooLarge => "Request Header Fields Too Large",
+            #[cfg(feature = "nginx")]
+            HttpCode::NoResponse => "No Response",
+            HttpCode::UnavailableForLegalReasons => "Unavailable For Legal Reasons",
+            #[cfg(feature = "nginx")]
+            HttpCode::ClientClosedRequest => "Client Closed Request",
+            HttpCode::InternalServerError => "Internal Server Error",
+            HttpCode::NotImplemented => "Not Implemented",
+            HttpCode::BadGateway => "Bad Gateway",
+            HttpCode::ServiceUnavailable => "Service Unavailable",
+            HttpCode::GatewayTimeout => "Gateway Timeout",
+            HttpCode::HttpVersionNotSupported => "HTTP Version Not Supported",
+            HttpCode::VariantAlsoNegotiates => "Variant Also Negotiates",
+            #[cfg(feature = "webdav")]
+            HttpCode::InsufficientStorage => "Insufficient Storage",
+            #[cfg(feature = "webdav")]
+            HttpCode::LoopDetected => "Loop Detected",
+            HttpCode::NotExtended => "Not Extended",
+            HttpCode::NetworkAuthenticationRequired => "Network Authentication Required",
+            HttpCode::None | HttpCode::Unknown(_) => "",
+        }
+    }
+
+    /// Extracts the code from a line of an access log in common or combined format
+    ///
+    /// Fields are separated by whitespace, except the date between brackets and the request
+    /// between quotes which are read as a single field. The status is the sixth field:
+    ///
+    /// ```text
+    /// 127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /index.html HTTP/1.0" 200 2326
+    /// ```
+    ///
+    /// Malformed lines give `HttpCode::None`.
+    pub fn from_common_log_line(line: &str) -> HttpCode {
+        log_field(line, 5)
+            .filter(|status| status.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|status| status.parse::<u32>().ok())
+            .into()
+    }
+
+    /// Checks if the body of the response, if any, describes an error instead of the content
+    ///
+    /// This is the case for client and server errors.
+    pub fn body_is_error_detail(&self) -> bool {
+        self.is_client_error() || self.is_server_error()
+    }
+
+    /// Returns the log severity of the code, based on its class
+    ///
+    /// Missing and unknown codes give the default severity, [`Severity::Warning`].
+    pub fn severity(&self) -> Severity {
+        self.to_class().into()
+    }
+
+    /// Returns the codes that are commonly confused with this one
+    ///
+    /// This is useful for "did you mean" suggestions or to link related codes in documentation.
+    /// Codes without any usual confusion give an empty slice.
+    pub fn commonly_confused_with(&self) -> &'static [HttpCode] {
+        match self {
+            HttpCode::Unauthorized => &[HttpCode::Forbidden],
+            HttpCode::Forbidden => &[HttpCode::Unauthorized],
+            HttpCode::MovedPermanently => &[
+                HttpCode::Found,
+                HttpCode::TemporaryRedirect,
+                HttpCode::PermanentRedirect,
+            ],
+            HttpCode::Found => &[
+                HttpCode::MovedPermanently,
+                HttpCode::TemporaryRedirect,
+                HttpCode::PermanentRedirect,
+            ],
+            HttpCode::TemporaryRedirect => &[
+                HttpCode::MovedPermanently,
+                HttpCode::Found,
+                HttpCode::PermanentRedirect,
+            ],
+            HttpCode::PermanentRedirect => &[
+                HttpCode::MovedPermanently,
+                HttpCode::Found,
+                HttpCode::TemporaryRedirect,
+            ],
+            #[cfg(feature = "webdav")]
+            HttpCode::BadRequest => &[HttpCode::UnprocessableContent],
+            #[cfg(feature = "webdav")]
+            HttpCode::UnprocessableContent => &[HttpCode::BadRequest],
+            HttpCode::BadGateway => &[HttpCode::GatewayTimeout],
+            HttpCode::GatewayTimeout => &[HttpCode::BadGateway],
+            _ => &[],
+        }
+    }
+
+    /// Checks if the code is a valid answer to a `TRACE` request
+    ///
+    /// A successful `TRACE` gets a `200 OK` whose body, of type `message/http`, is the request
+    /// as received by the server. Errors are valid too, like `405 Method Not Allowed` when the
+    /// server disabled `TRACE`, but redirections and other successful codes are not.
+    pub fn valid_trace_response(&self) -> bool {
+        matches!(self, HttpCode::Ok) || self.is_client_error() || self.is_server_error()
+    }
+
+    /// Returns a minimal body for an error page, like `404 Not Found`
+    ///
+    /// This is the same text as [`Display`], but only for client and server errors: other codes
+    /// usually don't need an error page and give an empty string.
+    pub fn default_body(&self) -> String {
+        if self.body_is_error_detail() {
+            self.to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    /// Returns a key sorting codes by class, then by numeric value
+    ///
+    /// The key is the class digit times 1000 plus the code. `None` has a class digit of 0 so it
+    /// sorts first and `Unknown` codes have a class digit of 9 so they sort last.
+    pub fn sort_key(&self) -> u32 {
+        let class_digit = match self.to_class() {
+            HttpCodeClass::None => 0,
+            HttpCodeClass::Informational => 1,
+            HttpCodeClass::Successful => 2,
+            HttpCodeClass::Redirection => 3,
+            HttpCodeClass::ClientError => 4,
+            HttpCodeClass::ServerError => 5,
+            HttpCodeClass::Unknown => 9,
+        };
+
+        (class_digit * 1000u32).saturating_add(self.code())
+    }
+
+    /// Checks if the response should invalidate the stored entries of the target URI
+    ///
+    /// Following RFC 9111 §4.4, a cache must invalidate its entries when an unsafe request (like
+    /// `POST`, `PUT` or `DELETE`) gets a non-error response, that is a 2xx or 3xx code. The
+    /// method of the request isn't known here, so this assumes the code answers an unsafe one.
+    pub fn invalidates_cache(&self) -> bool {
+        self.is_successful() || self.is_redirection()
+    }
+
+    /// Checks if a proxy must drop this response before forwarding it to an HTTP/1.0 client
+    ///
+    /// HTTP/1.0 has no interim responses, so every 1xx code must be stripped. The final response
+    /// that follows them is then forwarded as usual.
+    pub fn must_strip_for_http10(&self) -> bool {
+        self.is_informational()
+    }
+
+    /// Checks if the response is likely to carry a meaningful `Retry-After` header
+    ///
+    /// RFC 9110 §10.2.3 defines `Retry-After` for `503 Service Unavailable`, where it tells how
+    /// long the service will be down, and for redirections, where it tells how long to wait
+    /// before following them. RFC 6585 adds it to `429 Too Many Requests`. Among redirections,
+    /// only the permanent `301` and `308` are kept, since clients usually follow the others
+    /// immediately.
+    pub fn retry_after_expected(&self) -> bool {
+        matches!(
+            self,
+            HttpCode::TooManyRequests
+                | HttpCode::ServiceUnavailable
+                | HttpCode::MovedPermanently
+                | HttpCode::PermanentRedirect
+        )
+    }
+
+    /// Returns what a cache should do with the stored validators of the resource
+    ///
+    /// A `200 OK` carries fresh validators to store, a `304 Not Modified` confirms the stored
+    /// ones and any other code leaves them alone.
+    pub fn etag_action(&self) -> EtagAction {
+        match self {
+            HttpCode::Ok => EtagAction::Store,
+            HttpCode::NotModified => EtagAction::Confirm,
+            _ => EtagAction::Ignore,
+        }
+    }
+
+    /// Checks if the request may succeed when retried with another representation
+    ///
+    /// A `406 Not Acceptable` can be retried with a broader `Accept` header and a
+    /// `415 Unsupported Media Type` with a body in another `Content-Type`. Unlike a plain retry,
+    /// sending the exact same request again would fail the same way.
+    pub fn retry_with_different_representation(&self) -> bool {
+        matches!(
+            self,
+            HttpCode::NotAcceptable | HttpCode::UnsupportedMediaType
+        )
+    }
+
+    /// Parses a code from raw bytes, without validating them as UTF-8 first
+    ///
+    /// The input must be exactly three ASCII digits, anything else, including surrounding
+    /// whitespace, gives a [`ParseHttpCodeError::InvalidDigits`].
+    pub fn parse_bytes(bytes: &[u8]) -> Result<HttpCode, ParseHttpCodeError> {
+        match bytes {
+            [h, t, u] if h.is_ascii_digit() && t.is_ascii_digit() && u.is_ascii_digit() => {
+                let digit = |b: &u8| (b - b'0') as u32;
+                Ok((digit(h) * 100 + digit(t) * 10 + digit(u)).into())
+            }
+            _ => Err(ParseHttpCodeError::InvalidDigits),
+        }
+    }
+
+    /// Returns the status bucket used by ELK dashboards, from `"1xx"` to `"5xx"`
+    ///
+    /// Like Kibana range aggregations, the bucket only depends on the numeric value, so an
+    /// `Unknown(599)` is in `"5xx"`. `None` and values outside of 100-599 give `"other"`.
+    pub const fn elk_bucket(&self) -> &'static str {
+        match self.hundreds() {
+            1 => "1xx",
+            2 => "2xx",
+            3 => "3xx",
+            4 => "4xx",
+            5 => "5xx",
+            _ => "other",
+        }
+    }
+
+    /// Checks if the code is a standard answer to a successful `DELETE` request
+    ///
+    /// That is `200 OK` with a status message, `202 Accepted` when the deletion will happen
+    /// later or `204 No Content` when there is nothing more to say.
+    pub fn is_delete_success(&self) -> bool {
+        matches!(
+            self,
+            HttpCode::Ok | HttpCode::Accepted | HttpCode::NoContent
+        )
+    }
+
+    /// Checks if the code is a final rejection of a `CONNECT` request
+    ///
+    /// Any 2xx code means the proxy established the tunnel, while client and server errors, like
+    /// `403 Forbidden`, `405 Method Not Allowed` or `502 Bad Gateway`, mean it refused or failed
+    /// to reach the target.
+    pub fn is_connect_rejection(&self) -> bool {
+        self.is_client_error() || self.is_server_error()
+    }
+
+    /// Returns an RFC 5424 structured data element describing the code
+    ///
+    /// The element looks like `[http@32473 code="404" class="4xx"]`, where 32473 is the private
+    /// enterprise number reserved for documentation and `class` is the [`elk_bucket`] of the
+    /// code.
+    ///
+    /// [`elk_bucket`]: HttpCode::elk_bucket
+    pub fn syslog_sd(&self) -> String {
+        format!(
+            "[http@32473 code=\"{}\" class=\"{}\"]",
+            self.code(),
+            self.elk_bucket()
+        )
+    }
+
+    /// Returns the problem with the request method, if the code is about one
+    ///
+    /// After a `405 Method Not Allowed`, the client can look at the `Allow` header to pick
+    /// another method. After a `501 Not Implemented`, the server doesn't know the method at all.
+    pub fn method_problem(&self) -> Option<MethodProblem> {
+        match self {
+            HttpCode::MethodNotAllowed => Some(MethodProblem::NotAllowed),
+            HttpCode::NotImplemented => Some(MethodProblem::NotImplemented),
+            _ => None,
+        }
+    }
+
+    /// Returns the generic code of a class, like `400 Bad Request` for client errors
+    ///
+    /// This is the same as [`HttpCodeClass::canonical_code`], `None` and `Unknown` classes give
+    /// `HttpCode::None`.
+    pub fn generic(class: HttpCodeClass) -> HttpCode {
+        class.canonical_code()
+    }
+
+    /// Checks if following this redirection drops the request body
+    ///
+    /// A `303 See Other` forces the next request to be a `GET`, so the body is not sent again.
+    /// On the opposite, `307 Temporary Redirect` and `308 Permanent Redirect` require the same
+    /// method and body to be used. Historical `301` and `302` are left out since user agents
+    /// only change `POST` to `GET` for them.
+    pub fn redirect_drops_body(&self) -> bool {
+        matches!(self, HttpCode::SeeOther)
+    }
+
+    /// Returns a sentence describing the code, suitable for screen readers
+    ///
+    /// For example, `404 Not Found` gives `HTTP status 404, Not Found, a client error`.
+    pub fn aria_description(&self) -> String {
+        let class = match self.to_class() {
+            HttpCodeClass::Informational => "an informational response",
+            HttpCodeClass::Successful => "a successful response",
+            HttpCodeClass::Redirection => "a redirection",
+            HttpCodeClass::ClientError => "a client error",
+            HttpCodeClass::ServerError => "a server error",
+            HttpCodeClass::Unknown => "an unknown status",
+            HttpCodeClass::None => return "No HTTP status".to_string(),
+        };
+
+        match self {
+            HttpCode::Unknown(v) => format!("HTTP status {v}, {class}"),
+            _ => format!(
+                "HTTP status {}, {}, {class}",
+                self.code(),
+                self.reason_phrase()
+            ),
+        }
+    }
+
+    /// Iterates over every standard code, in ascending order
+    ///
+    /// `None` and `Unknown` codes are not included.
+    pub fn all() -> impl Iterator<Item = HttpCode> {
+        STANDARD_CODES.into_iter()
+    }
+
+    /// Returns the reason phrase of the code without its number, like `Not Found`
+    ///
+    /// This is an alias of [`HttpCode::reason_phrase`] that reads better when composing a custom
+    /// format. `None` and `Unknown` codes give an empty string.
+    pub const fn as_str(&self) -> &'static str {
+        self.reason_phrase()
+    }
+
+    /// Checks if the code is usual as a per-resource status inside a WebDAV `207 Multi-Status`
+    ///
+    /// Each `<propstat>` of a `PROPFIND` or `PROPPATCH` answer carries its own status, typically
+    /// `200 OK`, `403 Forbidden`, `404 Not Found`, `423 Locked` or, when another property of
+    /// the same request failed, `424 Failed Dependency`. Interim codes and redirections don't
+    /// belong there.
+    pub fn valid_in_multistatus(&self) -> bool {
+        match self {
+            HttpCode::Ok | HttpCode::Forbidden | HttpCode::NotFound => true,
+            #[cfg(feature = "webdav")]
+            HttpCode::Locked | HttpCode::FailedDependency => true,
+            _ => false,
+        }
+    }
+
+    /// Returns how the response should be counted in cache metrics
+    ///
+    /// A `304 Not Modified` is a hit, since the stored response was validated, while a
+    /// `200 OK` or a `206 Partial Content` coming from the origin is a miss. Other codes, like
+    /// errors, don't count.
+    pub fn cache_metric(&self) -> CacheMetric {
+        match self {
+            HttpCode::NotModified => CacheMetric::Hit,
+            HttpCode::Ok | HttpCode::PartialContent => CacheMetric::Miss,
+            _ => CacheMetric::NotApplicable,
+        }
+    }
+
+    /// Checks if the code reveals a server fault worth alerting the on-call team
+    ///
+    /// Every server error qualifies except `501 Not Implemented`: it means the client used a
+    /// method the server knowingly doesn't support, which is not an outage. Client errors never
+    /// qualify since they come from the requests, not the service.
+    pub fn should_page_oncall(&self) -> bool {
+        self.is_server_error() && !matches!(self, HttpCode::NotImplemented)
+    }
+
+    /// Checks if the response is an interim one, meaning the final response is still to come
+    ///
+    /// This is the case of every 1xx code, like `100 Continue` or `103 Early Hints`.
+    pub fn is_interim(&self) -> bool {
+        self.is_informational()
+    }
+
+    /// Returns the closest standard code, degrading unknown codes to their class
+    ///
+    /// An `Unknown` code between 100 and 599 gives the generic code of its hundreds, so
+    /// `Unknown(450)` gives `400 Bad Request`. Other codes, including `None` and `Unknown`
+    /// codes outside of this range, are returned as is.
+    pub fn closest_known(&self) -> HttpCode {
+        match self {
+            HttpCode::Unknown(v) if (100..600).contains(v) => HttpCode::from(v / 100 * 100),
+            _ => *self,
+        }
+    }
+
+    /// Checks if the code belongs to the given class
+    ///
+    /// Unknown codes only match [`HttpCodeClass::Unknown`] and the absence of code only matches
+    /// [`HttpCodeClass::None`].
+    pub fn matches_class(&self, class: HttpCodeClass) -> bool {
+        self.to_class() == class
+    }
+
+    /// Classifies a batch of numeric codes
+    ///
+    /// This gives the same result as converting each number to a code then to a class, but reads
+    /// the class straight from a table, which is much cheaper on large inputs like access logs.
+    ///
+    /// ```
+    /// use http_codex::{HttpCode, HttpCodeClass};
+    ///
+    /// assert_eq!(
+    ///     HttpCode::classify_many(&[200, 404, 599]),
+    ///     vec![
+    ///         HttpCodeClass::Successful,
+    ///         HttpCodeClass::ClientError,
+    ///         HttpCodeClass::Unknown
+    ///     ]
+    /// );
+    /// ```
+    pub fn classify_many(codes: &[u32]) -> Vec<HttpCodeClass> {
+        codes
+            .iter()
+            .map(|value| {
+                value
+                    .checked_sub(LOOKUP_OFFSET)
+                    .and_then(|i| CLASS_LOOKUP.get(i as usize))
+                    .copied()
+                    .unwrap_or(HttpCodeClass::Unknown)
+            })
+            .collect()
+    }
+
+    /// Checks if the code is a permanent redirection, which clients may cache and remember
+    ///
+    /// This is the case of `301 Moved Permanently` and `308 Permanent Redirect`.
+    pub fn is_permanent_redirect(&self) -> bool {
+        matches!(
+            self,
+            HttpCode::MovedPermanently | HttpCode::PermanentRedirect
+        )
+    }
+
+    /// Checks if the code is a temporary redirection, which must not be remembered
+    ///
+    /// This is the case of `302 Found`, `303 See Other` and `307 Temporary Redirect`.
+    pub fn is_temporary_redirect(&self) -> bool {
+        matches!(
+            self,
+            HttpCode::Found | HttpCode::SeeOther | HttpCode::TemporaryRedirect
+        )
+    }
+
+    /// Returns an emoji showing the class of the code, for terminal output
+    ///
+    /// Every code of a class share the same emoji, unknown codes and the absence of code get `❓`.
+    pub fn category_emoji(&self) -> &'static str {
+        match self.to_class() {
+            HttpCodeClass::Informational => "ℹ️",
+            HttpCodeClass::Successful => "✅",
+            HttpCodeClass::Redirection => "↪️",
+            HttpCodeClass::ClientError => "⚠️",
+            HttpCodeClass::ServerError => "💥",
+            HttpCodeClass::None | HttpCodeClass::Unknown => "❓",
+        }
+    }
+
+    /// Checks if the code asks the client to authenticate
+    ///
+    /// This is the case of `401 Unauthorized`, `407 Proxy Authentication Required` and
+    /// `511 Network Authentication Required`. A `403 Forbidden` is not included, authenticating
+    /// again won't change the answer.
+    pub fn requires_authentication(&self) -> bool {
+        matches!(
+            self,
+            HttpCode::Unauthorized
+                | HttpCode::ProxyAuthenticationRequired
+                | HttpCode::NetworkAuthenticationRequired
+        )
+    }
+
+    /// Builds the status line of a response for the given protocol version
+    ///
+    /// `HttpCode::Ok.status_line("HTTP/1.1")` gives `HTTP/1.1 200 OK`. `Unknown` codes have no
+    /// reason phrase and `None` gives an empty string.
+    pub fn status_line(&self, version: &str) -> String {
+        match self {
+            HttpCode::None => String::new(),
+            _ => format!("{version} {self}"),
+        }
+    }
+
+    /// Gathers the numeric value, reason phrase, class, description and specification of the code
+    ///
+    /// ```
+    /// use http_codex::{HttpCode, HttpCodeClass};
+    ///
+    /// let info = HttpCode::NotFound.describe();
+    ///
+    /// assert_eq!(info.code, 404);
+    /// assert_eq!(info.phrase, "Not Found");
+    /// assert_eq!(info.class, HttpCodeClass::ClientError);
+    /// ```
+    pub fn describe(&self) -> CodeInfo {
+        CodeInfo {
+            code: self.code(),
+            phrase: self.reason_phrase(),
+            class: self.to_class(),
+            description: self.description(),
+            rfc: self.rfc_reference(),
+        }
+    }
+
+    /// Checks if the code is a final response status, ending the exchange
+    ///
+    /// This is the case of every standard 2xx to 5xx code. Interim 1xx codes are followed by
+    /// another response, and `None` and `Unknown` codes are not meaningful statuses.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self.to_class(),
+            HttpCodeClass::Successful
+                | HttpCodeClass::Redirection
+                | HttpCodeClass::ClientError
+                | HttpCodeClass::ServerError
+        )
+    }
+
+    /// Converts a floating point number, like the ones some JSON parsers give, to a code
+    ///
+    /// Only whole numbers fitting a `u32` are accepted, `404.0` gives `HttpCode::NotFound` while
+    /// `404.5`, negative numbers, infinities and `NaN` give a
+    /// [`ParseHttpCodeError::InvalidNumber`].
+    pub fn try_from_f64(v: f64) -> Result<HttpCode, ParseHttpCodeError> {
+        if v.is_finite() && v.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(&v) {
+            Ok((v as u32).into())
+        } else {
+            Err(ParseHttpCodeError::InvalidNumber)
+        }
+    }
+
+    /// Counts the codes of each class
+    ///
+    /// The totals are in class order: `None`, `Informational`, `Successful`, `Redirection`,
+    /// `ClientError`, `ServerError` then `Unknown`.
+    ///
+    /// ```
+    /// use http_codex::HttpCode;
+    ///
+    /// let counts = HttpCode::group_counts([HttpCode::Ok, HttpCode::NotFound, HttpCode::Ok]);
+    ///
+    /// assert_eq!(counts, [0, 0, 2, 0, 1, 0, 0]);
+    /// ```
+    pub fn group_counts(codes: impl IntoIterator<Item = HttpCode>) -> [usize; 7] {
+        codes.into_iter().fold([0; 7], |mut counts, code| {
+            counts[code.to_class().rank() as usize] += 1;
+            counts
+        })
+    }
+
+    /// Returns the class of the code
+    ///
+    /// This never fails, codes added in later versions get their class as well, so it can be
+    /// used instead of matching every variant.
+    pub fn classify(&self) -> HttpCodeClass {
+        self.to_class()
+    }
+
+    /// Finds a code by its reason phrase, ignoring case
+    ///
+    /// ```
+    /// use http_codex::HttpCode;
+    ///
+    /// assert_eq!(HttpCode::from_reason_phrase("not found"), Some(HttpCode::NotFound));
+    /// assert_eq!(HttpCode::from_reason_phrase("Nonexistent"), None);
+    /// ```
+    pub fn from_reason_phrase(phrase: &str) -> Option<HttpCode> {
+        HttpCode::all().find(|code| code.reason_phrase().eq_ignore_ascii_case(phrase))
+    }
+
+    /// Returns a low cardinality label for metrics, from `"1xx"` to `"5xx"`
+    ///
+    /// Unlike [`HttpCode::elk_bucket`], this follows the class of the code, so every `Unknown`
+    /// code gives `"unknown"` and `None` gives `"none"`.
+    pub const fn metric_label(&self) -> &'static str {
+        match self {
+            HttpCode::None => "none",
+            HttpCode::Unknown(_) => "unknown",
+            _ => match self.hundreds() {
+                1 => "1xx",
+                2 => "2xx",
+                3 => "3xx",
+                4 => "4xx",
+                5 => "5xx",
+                _ => "unknown",
+            },
+        }
+    }
+
+    /// Drops unknown codes that can't be HTTP statuses
+    ///
+    /// An `Unknown` code outside of 100-599 becomes `HttpCode::None`, as if no code was received.
+    /// Unknown codes in that range and standard codes are kept as they are.
+    pub fn normalized(&self) -> HttpCode {
+        match self {
+            HttpCode::Unknown(v) if !(100..=599).contains(v) => HttpCode::None,
+            _ => *self,
+        }
+    }
+
+    /// Gives the code back as `Ok` if it is 2xx, or as `Err` otherwise
+    ///
+    /// `None` and `Unknown` codes are errors as well, so `code.ensure_success()?` stops on any
+    /// unsuccessful response with the offending code.
+    pub fn ensure_success(self) -> Result<HttpCode, HttpCode> {
+        if self.is_successful() {
+            Ok(self)
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Checks if the code comes from the WebDAV specifications
+    ///
+    /// This is the case of `102`, `207`, `208`, `422`, `423`, `424`, `507` and `508`. Even if
+    /// `422 Unprocessable Content` is now part of the HTTP semantics, it was first defined for
+    /// WebDAV.
+    ///
+    /// These codes only exist with the `webdav` feature, without it this always gives `false`.
+    pub fn is_webdav(&self) -> bool {
+        match self {
+            #[cfg(feature = "webdav")]
+            HttpCode::Processing
+            | HttpCode::MultiStatus
+            | HttpCode::AlreadyReported
+            | HttpCode::UnprocessableContent
+            | HttpCode::Locked
+            | HttpCode::FailedDependency
+            | HttpCode::InsufficientStorage
+            | HttpCode::LoopDetected => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the standard code following this one, by numeric value
+    ///
+    /// Unknown codes give the first standard code above their value and `None` gives the first
+    /// standard code. The last standard code gives `None`.
+    pub fn next_known(&self) -> Option<HttpCode> {
+        let value = self.code();
+
+        HttpCode::KNOWN_CODES
+            .iter()
+            .find(|(v, _)| *v > value)
+            .map(|(_, code)| *code)
+    }
+
+    /// Returns the standard code preceding this one, by numeric value
+    ///
+    /// Unknown codes give the last standard code below their value. The first standard code and
+    /// `None` give `None`.
+    pub fn prev_known(&self) -> Option<HttpCode> {
+        let value = self.code();
+
+        HttpCode::KNOWN_CODES
+            .iter()
+            .rev()
+            .find(|(v, _)| *v < value)
+            .map(|(_, code)| *code)
+    }
+
+    /// Reads a code from an environment variable
+    ///
+    /// The value can be a number, like `503`, or a variant name, like `service-unavailable`.
+    /// Unset variables and values that are neither give `None`.
+    pub fn from_env(var: &str) -> Option<HttpCode> {
+        let value = std::env::var(var).ok()?;
+        let value = value.trim();
+
+        match value.parse::<u32>() {
+            Ok(code) => Some(code.into()),
+            Err(_) => HttpCode::try_from(value).ok(),
+        }
+    }
+
+    /// Checks if sending the same request again later may succeed
+    ///
+    /// This is the case of the codes having a [`HttpCode::retry_suggestion`]: `429`, `502`, `503`
+    /// and `504`.
+    pub fn is_retryable(&self) -> bool {
+        self.retry_suggestion().is_some()
+    }
+
+    /// Checks if the code reports a failed content negotiation
+    ///
+    /// A `406 Not Acceptable` means no representation matches the `Accept` headers and a
+    /// `415 Unsupported Media Type` means the request body `Content-Type` is refused. These are
+    /// the codes [`HttpCode::retry_with_different_representation`] accepts, which this method
+    /// delegates to.
+    pub fn is_negotiation_failure(&self) -> bool {
+        self.retry_with_different_representation()
+    }
+
+    /// Returns the numeric value of the code if it fits an HTTP status, from 100 to 999
+    ///
+    /// This is the value the web framework integrations build their status codes from, `None`
+    /// and `Unknown` codes outside of this range give `None`.
+    #[cfg_attr(
+        not(any(
+            feature = "actix",
+            feature = "axum",
+            feature = "http",
+            feature = "hyper"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) const fn status_u16(&self) -> Option<u16> {
+        match self.code() {
+            code @ 100..=999 => Some(code as u16),
+            _ => None,
+        }
+    }
+}
+
+impl From<HttpCode> for u32 {
+    fn from(value: HttpCode) -> Self {
+        value.code()
+    }
+}
+
+impl From<HttpCode> for Option<u32> {
+    fn from(value: HttpCode) -> Self {
+        match value {
+            HttpCode::None => None,
+            _ => Some(value.into()),
+        }
+    }
+}
+
+impl From<HttpCode> for u16 {
+    fn from(value: HttpCode) -> Self {
+        u32::from(value) as u16
+    }
+}
+
+impl From<HttpCode> for i32 {
+    /// Converts the code to a signed integer
+    ///
+    /// An `Unknown` code above `i32::MAX` saturates to `i32::MAX` instead of wrapping to a
+    /// negative value.
+    fn from(value: HttpCode) -> Self {
+        i32::try_from(u32::from(value)).unwrap_or(i32::MAX)
+    }
+}
+
+impl From<HttpCode> for i64 {
+    fn from(value: HttpCode) -> Self {
+        u32::from(value).into()
+    }
+}
+
+impl Debug for HttpCode {
+    /// Writes the variant name followed by the numeric code, like `NotFound(404)`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpCode::None => write!(f, "None"),
+            _ => write!(f, "{}({})", self.name(), self.code()),
+        }
+    }
+}
+
+impl Display for HttpCode {
+    /// Writes the code followed by its reason phrase, like `404 Not Found`
+    ///
+    /// The alternate form (`{:#}`) only writes the number, like `404`. `Unknown` codes are
+    /// always written as a bare number and `None` writes nothing.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpCode::None => Ok(()),
+            HttpCode::Unknown(v) => write!(f, "{v}"),
+            _ if f.alternate() => write!(f, "{}", self.code()),
+            _ => write!(f, "{} {}", self.code(), self.reason_phrase()),
+        }
+    }
+}
+
+impl PartialEq<u32> for HttpCode {
+    fn eq(&self, other: &u32) -> bool {
+        u32::from(*self) == *other
+    }
+}
+
+impl PartialEq<HttpCode> for u32 {
+    fn eq(&self, other: &HttpCode) -> bool {
+        *self == u32::from(*other)
+    }
+}
+
+impl PartialEq<HttpCodeClass> for HttpCode {
+    /// Checks if the code belongs to the class, see [`HttpCode::matches_class`]
+    fn eq(&self, other: &HttpCodeClass) -> bool {
+        self.matches_class(*other)
+    }
+}
+
+impl From<HttpCode> for std::io::Error {
+    /// Creates an IO error with the code as message
+    ///
+    /// Timeouts give [`ErrorKind::TimedOut`](std::io::ErrorKind::TimedOut), `401` and `403` give
+    /// [`ErrorKind::PermissionDenied`](std::io::ErrorKind::PermissionDenied), `404` gives
+    /// [`ErrorKind::NotFound`](std::io::ErrorKind::NotFound) and any other code gives
+    /// [`ErrorKind::Other`](std::io::ErrorKind::Other).
+    fn from(value: HttpCode) -> Self {
+        let kind = match value {
+            HttpCode::RequestTimeout | HttpCode::GatewayTimeout => std::io::ErrorKind::TimedOut,
+            HttpCode::Unauthorized | HttpCode::Forbidden => std::io::ErrorKind::PermissionDenied,
+            HttpCode::NotFound => std::io::ErrorKind::NotFound,
+            _ => std::io::ErrorKind::Other,
+        };
+
+        std::io::Error::new(kind, value.to_string())
+    }
+}
+
+impl From<HttpCode> for String {
+    fn from(value: HttpCode) -> Self {
+        value.to_string()
+    }
+}
+
+impl Ord for HttpCodeClass {
+    /// Orders classes by their hundreds digit, with `None` first and `Unknown` last
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for HttpCodeClass {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for HttpCodeClass {
+    type Err = ParseHttpCodeError;
+
+    /// Finds a class by its name or its hundreds digit
+    ///
+    /// Names ignore case, spaces, underscores and hyphens, so `"Client Error"` and
+    /// `"client-error"` both give `HttpCodeClass::ClientError`, as do `"4"` and `"4xx"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name: String = s
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '_' | '-'))
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        let digit = name.strip_suffix("xx").unwrap_or(&name);
+
+        match (digit, name.as_str()) {
+            ("1", _) | (_, "informational") => Ok(HttpCodeClass::Informational),
+            ("2", _) | (_, "successful") => Ok(HttpCodeClass::Successful),
+            ("3", _) | (_, "redirection") => Ok(HttpCodeClass::Redirection),
+            ("4", _) | (_, "clienterror") => Ok(HttpCodeClass::ClientError),
+            ("5", _) | (_, "servererror") => Ok(HttpCodeClass::ServerError),
+            (_, "none") => Ok(HttpCodeClass::None),
+            (_, "unknown") => Ok(HttpCodeClass::Unknown),
+            _ => Err(ParseHttpCodeError::UnknownClass),
+        }
+    }
+}
+
+impl From<HttpCode> for HttpCodeClass {
+    fn from(value: HttpCode) -> Self {
+        match value {
+            HttpCode::Continue | HttpCode::SwitchingProtocols | HttpCode::EarlyHints => {
+                HttpCodeClass::Informational
+            }
+
+            HttpCode::Ok
+            | HttpCode::Created
+            | HttpCode::Accepted
+            | HttpCode::NonAuthoritativeInformation
+            | HttpCode::NoContent
+            | HttpCode::ResetContent
+            | HttpCode::PartialContent
+            | HttpCode::ImUsed => HttpCodeClass::Successful,
+
+            HttpCode::MultipleChoices
+            | HttpCode::MovedPermanently
+            | HttpCode::Found
+            | HttpCode::SeeOther
+            | HttpCode::NotModified
+            | HttpCode::TemporaryRedirect
+            | HttpCode::PermanentRedirect => HttpCodeClass::Redirection,
+
+            HttpCode::BadRequest
+            | HttpCode::Unauthorized
+            | HttpCode::PaymentRequired
+            | HttpCode::Forbidden
+            | HttpCode::NotFound
             | HttpCode::MethodNotAllowed
             | HttpCode::NotAcceptable
-            | HttpCode::ProxyAuthentificationRequired
+            | HttpCode::ProxyAuthenticationRequired
             | HttpCode::RequestTimeout
             | HttpCode::Conflict
             | HttpCode::Gone
@@ -828,9 +2323,6 @@ impl From<HttpCode> for HttpCodeClass {
             | HttpCode::ExpectationFailed
             | HttpCode::ImATeapot
             | HttpCode::MisdirectedRequest
-            | HttpCode::UnprocessableContent
-            | HttpCode::Locked
-            | HttpCode::FailedDependency
             | HttpCode::TooEarly
             | HttpCode::UpgradeRequired
             | HttpCode::PreconditionRequired
@@ -838,94 +2330,1305 @@ impl From<HttpCode> for HttpCodeClass {
             | HttpCode::RequestHeaderFieldsTooLarge
             | HttpCode::UnavailableForLegalReasons => HttpCodeClass::ClientError,
 
-            HttpCode::InternalServerError
-            | HttpCode::NotImplemented
-            | HttpCode::BadGateway
-            | HttpCode::ServiceUnavailable
-            | HttpCode::GatewayTimeout
-            | HttpCode::HttpVersionNotSupported
-            | HttpCode::VariantAlsoNegotiates
-            | HttpCode::InsufficientStorage
-            | HttpCode::LoopDetected
-            | HttpCode::NotExtended
-            | HttpCode::NetworkAuthetificationRequired => HttpCodeClass::ServerError,
+            #[cfg(feature = "nginx")]
+            HttpCode::NoResponse | HttpCode::ClientClosedRequest => HttpCodeClass::ClientError,
+
+            HttpCode::InternalServerError
+            | HttpCode::NotImplemented
+            | HttpCode::BadGateway
+            | HttpCode::ServiceUnavailable
+            | HttpCode::GatewayTimeout
+            | HttpCode::HttpVersionNotSupported
+            | HttpCode::VariantAlsoNegotiates
+            | HttpCode::NotExtended
+            | HttpCode::NetworkAuthenticationRequired => HttpCodeClass::ServerError,
+
+            #[cfg(feature = "webdav")]
+            HttpCode::Processing => HttpCodeClass::Informational,
+
+            #[cfg(feature = "webdav")]
+            HttpCode::MultiStatus | HttpCode::AlreadyReported => HttpCodeClass::Successful,
+
+            #[cfg(feature = "webdav")]
+            HttpCode::UnprocessableContent | HttpCode::Locked | HttpCode::FailedDependency => {
+                HttpCodeClass::ClientError
+            }
+
+            #[cfg(feature = "webdav")]
+            HttpCode::InsufficientStorage | HttpCode::LoopDetected => HttpCodeClass::ServerError,
+
+            HttpCode::None => HttpCodeClass::None,
+            HttpCode::Unknown(_) => HttpCodeClass::Unknown,
+        }
+    }
+}
+
+impl From<HttpCodeClass> for u8 {
+    /// Gives the leading digit of the class codes, `0` for `None` and `Unknown`
+    fn from(value: HttpCodeClass) -> Self {
+        match value {
+            HttpCodeClass::Informational => 1,
+            HttpCodeClass::Successful => 2,
+            HttpCodeClass::Redirection => 3,
+            HttpCodeClass::ClientError => 4,
+            HttpCodeClass::ServerError => 5,
+            HttpCodeClass::None | HttpCodeClass::Unknown => 0,
+        }
+    }
+}
+
+impl HttpCodeClass {
+    /// Returns `true` if the class is Informational
+    pub fn is_informational(&self) -> bool {
+        matches!(self, HttpCodeClass::Informational)
+    }
+
+    /// Returns `true` if the class is Successful
+    pub fn is_successful(&self) -> bool {
+        matches!(self, HttpCodeClass::Successful)
+    }
+
+    /// Returns `true` if the class is Redirection
+    pub fn is_redirection(&self) -> bool {
+        matches!(self, HttpCodeClass::Redirection)
+    }
+
+    /// Returns `true` if the class is ClientError
+    pub fn is_client_error(&self) -> bool {
+        matches!(self, HttpCodeClass::ClientError)
+    }
+
+    /// Returns `true` if the class is ServerError
+    pub fn is_server_error(&self) -> bool {
+        matches!(self, HttpCodeClass::ServerError)
+    }
+
+    /// Returns `true` if the class is Unknown
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, HttpCodeClass::Unknown)
+    }
+
+    /// Returns `true` if the class is None
+    pub fn is_none(&self) -> bool {
+        matches!(self, HttpCodeClass::None)
+    }
+
+    /// Returns the canonical code of the class (`x00`)
+    ///
+    /// `None` and `Unknown` classes have no canonical code and give `HttpCode::None`.
+    pub fn canonical_code(&self) -> HttpCode {
+        match self {
+            HttpCodeClass::Informational => HttpCode::Continue,
+            HttpCodeClass::Successful => HttpCode::Ok,
+            HttpCodeClass::Redirection => HttpCode::MultipleChoices,
+            HttpCodeClass::ClientError => HttpCode::BadRequest,
+            HttpCodeClass::ServerError => HttpCode::InternalServerError,
+            HttpCodeClass::None | HttpCodeClass::Unknown => HttpCode::None,
+        }
+    }
+
+    /// Iterates over the standard codes of the class, in ascending order
+    ///
+    /// `None` and `Unknown` classes have no standard code and yield nothing.
+    pub fn codes(&self) -> impl Iterator<Item = HttpCode> {
+        let class = *self;
+        HttpCode::all().filter(move |code| code.to_class() == class)
+    }
+
+    /// Position of the class when sorting, `None` first and `Unknown` last
+    fn rank(&self) -> u8 {
+        match self {
+            HttpCodeClass::None => 0,
+            HttpCodeClass::Informational => 1,
+            HttpCodeClass::Successful => 2,
+            HttpCodeClass::Redirection => 3,
+            HttpCodeClass::ClientError => 4,
+            HttpCodeClass::ServerError => 5,
+            HttpCodeClass::Unknown => 6,
+        }
+    }
+
+    /// Returns the numeric value of the canonical code of the class, like `400` for client errors
+    ///
+    /// This is the value of [`HttpCodeClass::canonical_code`], `None` and `Unknown` classes give
+    /// `None`.
+    pub fn representative_code(&self) -> Option<u32> {
+        match self.canonical_code() {
+            HttpCode::None => None,
+            code => Some(code.code()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::http_code::CODE_TABLE;
+    use crate::{
+        CacheMetric, EtagAction, HttpCode, HttpCodeClass, MethodProblem, ParseHttpCodeError,
+    };
+    use std::num::{NonZeroU16, NonZeroU32};
+
+    #[test]
+    fn uint_to_code() {
+        assert!(matches!(HttpCode::from(307), HttpCode::TemporaryRedirect));
+        assert!(matches!(HttpCode::from(410), HttpCode::Gone));
+        assert!(matches!(
+            HttpCode::from(451),
+            HttpCode::UnavailableForLegalReasons
+        ));
+        assert!(matches!(HttpCode::from(504), HttpCode::GatewayTimeout));
+        assert!(matches!(HttpCode::from(999), HttpCode::Unknown(999)));
+    }
+
+    #[test]
+    fn option_to_code() {
+        let none: Option<u32> = None;
+
+        assert!(matches!(HttpCode::from(Some(103)), HttpCode::EarlyHints));
+        assert!(matches!(HttpCode::from(Some(200)), HttpCode::Ok));
+        assert!(matches!(HttpCode::from(Some(226)), HttpCode::ImUsed));
+        assert!(matches!(HttpCode::from(Some(302)), HttpCode::Found));
+        assert!(matches!(HttpCode::from(none), HttpCode::None));
+    }
+
+    #[test]
+    fn code_to_uint() {
+        assert_eq!(u32::from(HttpCode::SwitchingProtocols), 101);
+        assert_eq!(u32::from(HttpCode::NoContent), 204);
+        assert_eq!(u32::from(HttpCode::MultipleChoices), 300);
+        assert_eq!(u32::from(HttpCode::NotFound), 404);
+        assert_eq!(u32::from(HttpCode::RangeNotSatisfiable), 416);
+    }
+
+    #[test]
+    #[cfg(feature = "nginx")]
+    fn nginx_codes() {
+        assert!(matches!(HttpCode::from(444), HttpCode::NoResponse));
+        assert!(matches!(HttpCode::from(499), HttpCode::ClientClosedRequest));
+        assert_eq!(u32::from(HttpCode::NoResponse), 444);
+        assert_eq!(u32::from(HttpCode::ClientClosedRequest), 499);
+        assert!(HttpCode::ClientClosedRequest.is_client_error());
+    }
+
+    #[test]
+    #[cfg(not(feature = "nginx"))]
+    fn nginx_codes_disabled() {
+        assert!(matches!(HttpCode::from(444), HttpCode::Unknown(444)));
+        assert!(matches!(HttpCode::from(499), HttpCode::Unknown(499)));
+    }
+
+    #[test]
+    #[cfg(feature = "webdav")]
+    fn webdav_codes() {
+        assert!(matches!(HttpCode::from(207), HttpCode::MultiStatus));
+        assert!(matches!(HttpCode::from(423), HttpCode::Locked));
+        assert_eq!(u32::from(HttpCode::LoopDetected), 508);
+        assert!(HttpCode::InsufficientStorage.is_server_error());
+        assert_eq!(HttpCode::MultiStatus.rfc_reference(), Some("RFC 4918"));
+        assert_eq!(HttpCode::Locked.rfc_reference(), Some("RFC 4918"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "webdav"))]
+    fn webdav_codes_disabled() {
+        for value in [102, 207, 208, 422, 423, 424, 507, 508] {
+            assert_eq!(HttpCode::from(value), HttpCode::Unknown(value));
+            assert!(HttpCode::from(value).is_unknown());
+        }
+    }
+
+    #[test]
+    fn conditional_response() {
+        assert!(HttpCode::NotModified.is_conditional_response());
+        assert!(HttpCode::PreconditionFailed.is_conditional_response());
+        assert!(!HttpCode::Ok.is_conditional_response());
+    }
+
+    #[test]
+    fn description() {
+        assert!(HttpCode::NotFound
+            .description()
+            .starts_with("The server cannot find the requested resource."));
+        assert_eq!(
+            HttpCode::ImATeapot.description(),
+            "The server refuses the attempt to brew coffee with a teapot."
+        );
+        assert_eq!(HttpCode::None.description(), "");
+        assert_eq!(HttpCode::Unknown(999).description(), "");
+        assert!(HttpCode::all().all(|code| !code.description().is_empty()));
+
+        const TEAPOT: &str = HttpCode::ImATeapot.description();
+        assert!(TEAPOT.contains("teapot"));
+    }
+
+    #[test]
+    fn clear_credentials() {
+        assert!(HttpCode::Unauthorized.should_clear_credentials());
+        assert!(!HttpCode::Forbidden.should_clear_credentials());
+    }
+
+    #[test]
+    fn name_to_code() {
+        assert!(matches!(
+            HttpCode::try_from("NotFound"),
+            Ok(HttpCode::NotFound)
+        ));
+        assert!(matches!(
+            HttpCode::try_from("not-found"),
+            Ok(HttpCode::NotFound)
+        ));
+        assert!(matches!(
+            HttpCode::try_from("not_found"),
+            Ok(HttpCode::NotFound)
+        ));
+        assert!(matches!(
+            HttpCode::try_from("IM_A_TEAPOT"),
+            Ok(HttpCode::ImATeapot)
+        ));
+        assert_eq!(
+            HttpCode::try_from("bad_name").err(),
+            Some(ParseHttpCodeError::UnknownName)
+        );
+        assert!(HttpCode::try_from("404").is_err());
+        assert!(HttpCode::try_from("not_foundd").is_err());
+        assert!(HttpCode::try_from("").is_err());
+    }
+
+    #[test]
+    fn retry_suggestion() {
+        assert_eq!(
+            HttpCode::TooManyRequests.retry_suggestion(),
+            Some("You are being rate limited; wait before retrying")
+        );
+        assert_eq!(
+            HttpCode::ServiceUnavailable.retry_suggestion(),
+            Some("Retry after a short delay")
+        );
+        assert_eq!(HttpCode::NotFound.retry_suggestion(), None);
+    }
+
+    #[test]
+    fn code_to_u16() {
+        assert_eq!(u16::from(HttpCode::NotFound), 404);
+        assert_eq!(u16::from(HttpCode::Ok), 200);
+        assert_eq!(u16::from(HttpCode::None), 0);
+        assert_eq!(u16::from(HttpCode::Unknown(599)), 599);
+    }
+
+    #[test]
+    fn connection_teardown() {
+        assert!(HttpCode::MisdirectedRequest.suggests_connection_teardown());
+        assert!(HttpCode::HttpVersionNotSupported.suggests_connection_teardown());
+        assert!(!HttpCode::NotFound.suggests_connection_teardown());
+    }
+
+    #[test]
+    fn non_zero_to_code() {
+        assert!(matches!(
+            HttpCode::from(NonZeroU16::new(404).unwrap()),
+            HttpCode::NotFound
+        ));
+        assert!(matches!(
+            HttpCode::from(NonZeroU32::new(201).unwrap()),
+            HttpCode::Created
+        ));
+        assert!(matches!(
+            HttpCode::from(NonZeroU32::new(999).unwrap()),
+            HttpCode::Unknown(999)
+        ));
+    }
+
+    #[test]
+    fn class_canonical_code() {
+        assert_eq!(
+            u32::from(HttpCodeClass::Informational.canonical_code()),
+            100
+        );
+        assert_eq!(u32::from(HttpCodeClass::Successful.canonical_code()), 200);
+        assert_eq!(u32::from(HttpCodeClass::Redirection.canonical_code()), 300);
+        assert_eq!(u32::from(HttpCodeClass::ClientError.canonical_code()), 400);
+        assert_eq!(u32::from(HttpCodeClass::ServerError.canonical_code()), 500);
+        assert!(HttpCodeClass::None.canonical_code().is_none());
+        assert!(HttpCodeClass::Unknown.canonical_code().is_none());
+    }
+
+    #[test]
+    fn compare_with_uint() {
+        assert!(HttpCode::NotFound == 404);
+        assert!(404 == HttpCode::NotFound);
+        assert!(HttpCode::NotFound != 403);
+        assert!(HttpCode::None == 0);
+        assert!(HttpCode::Unknown(599) == 599);
+        assert!(599 == HttpCode::Unknown(599));
+    }
+
+    #[test]
+    fn needs_code_change() {
+        assert!(HttpCode::BadRequest.needs_code_change());
+        assert!(HttpCode::HttpVersionNotSupported.needs_code_change());
+        assert!(!HttpCode::ServiceUnavailable.needs_code_change());
+        assert!(!HttpCode::TooManyRequests.needs_code_change());
+    }
+
+    #[test]
+    fn hundreds() {
+        assert_eq!(HttpCode::NoContent.hundreds(), 2);
+        assert_eq!(HttpCode::NotFound.hundreds(), 4);
+        assert_eq!(HttpCode::ServiceUnavailable.hundreds(), 5);
+        assert_eq!(HttpCode::None.hundreds(), 0);
+        assert_eq!(HttpCode::Unknown(599).hundreds(), 5);
+        assert_eq!(HttpCode::Unknown(42).hundreds(), 0);
+    }
+
+    #[test]
+    fn const_hundreds() {
+        const HUNDREDS: u32 = HttpCode::NotFound.hundreds();
+
+        assert_eq!(HUNDREDS, 4);
+        assert_eq!(HttpCode::None.hundreds(), 0);
+        assert_eq!(HttpCode::Unknown(512).hundreds(), 5);
+        assert_eq!(HttpCode::Unknown(700).hundreds(), 7);
+    }
+
+    #[test]
+    fn negotiation_relevant() {
+        assert!(HttpCode::MultipleChoices.negotiation_relevant());
+        assert!(HttpCode::NotAcceptable.negotiation_relevant());
+        assert!(!HttpCode::NotFound.negotiation_relevant());
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", HttpCode::NotFound), "NotFound(404)");
+        assert_eq!(format!("{:?}", HttpCode::Unknown(599)), "Unknown(599)");
+        assert_eq!(format!("{:?}", HttpCode::None), "None");
+    }
+
+    #[test]
+    fn rfc_reference() {
+        assert_eq!(HttpCode::NotFound.rfc_reference(), Some("RFC 9110"));
+        assert_eq!(
+            HttpCode::PermanentRedirect.rfc_reference(),
+            Some("RFC 9110")
+        );
+        assert_eq!(HttpCode::ImATeapot.rfc_reference(), Some("RFC 2324"));
+        assert_eq!(HttpCode::TooManyRequests.rfc_reference(), Some("RFC 6585"));
+        assert_eq!(HttpCode::None.rfc_reference(), None);
+        assert_eq!(HttpCode::Unknown(599).rfc_reference(), None);
+    }
+
+    #[test]
+    fn grpc_web_trailer() {
+        assert_eq!(HttpCode::NotFound.to_grpc_web_trailer(), "grpc-status: 5");
+        assert_eq!(HttpCode::Ok.to_grpc_web_trailer(), "grpc-status: 0");
+        assert_eq!(HttpCode::ImATeapot.to_grpc_web_trailer(), "grpc-status: 2");
+    }
+
+    #[test]
+    fn is_standard() {
+        assert!(HttpCode::NotFound.is_standard());
+        assert!(!HttpCode::Unknown(599).is_standard());
+        assert!(!HttpCode::None.is_standard());
+    }
+
+    #[test]
+    fn close_connection() {
+        assert!(HttpCode::PayloadTooLarge.should_close_connection());
+        assert!(HttpCode::RequestTimeout.should_close_connection());
+        assert!(!HttpCode::Ok.should_close_connection());
+    }
+
+    #[test]
+    fn code_to_string() {
+        assert_eq!(String::from(HttpCode::Ok), "200 OK");
+        assert_eq!(String::from(HttpCode::NotFound), "404 Not Found");
+        assert_eq!(
+            String::from(HttpCode::HttpVersionNotSupported),
+            "505 HTTP Version Not Supported"
+        );
+        assert_eq!(String::from(HttpCode::Unknown(599)), "599");
+        assert_eq!(String::from(HttpCode::None), "");
+        assert_eq!(
+            HttpCode::NotFound.to_string(),
+            String::from(HttpCode::NotFound)
+        );
+    }
+
+    #[test]
+    fn common_log_line() {
+        let combined = r#"127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /apache_pb.gif HTTP/1.0" 200 2326 "http://www.example.com/start.html" "Mozilla/4.08 [en] (Win98; I ;Nav)""#;
+        let common = r#"10.0.0.1 - - [10/Oct/2000:13:55:36 -0700] "POST /login HTTP/1.1" 404 0"#;
+
+        assert!(matches!(
+            HttpCode::from_common_log_line(combined),
+            HttpCode::Ok
+        ));
+        assert!(matches!(
+            HttpCode::from_common_log_line(common),
+            HttpCode::NotFound
+        ));
+        assert!(HttpCode::from_common_log_line("").is_none());
+        assert!(HttpCode::from_common_log_line("127.0.0.1 - - [date").is_none());
+        assert!(HttpCode::from_common_log_line(r#"a - - [d] "GET /" abc 0"#).is_none());
+    }
+
+    #[test]
+    fn body_is_error_detail() {
+        assert!(HttpCode::InternalServerError.body_is_error_detail());
+        assert!(HttpCode::NotFound.body_is_error_detail());
+        assert!(!HttpCode::Ok.body_is_error_detail());
+    }
 
-            HttpCode::None => HttpCodeClass::None,
-            HttpCode::Unknown(_) => HttpCodeClass::Unknown,
+    #[test]
+    fn code_table() {
+        assert!(CODE_TABLE.windows(2).all(|w| w[0].0 < w[1].0));
+
+        for (value, code) in CODE_TABLE {
+            assert_eq!(u32::from(*code), *value);
+            assert_eq!(u32::from(HttpCode::from(*value)), *value);
+            assert!(HttpCode::from(*value).is_standard());
+        }
+
+        for value in [
+            0,
+            1,
+            99,
+            104,
+            199,
+            299,
+            305,
+            306,
+            419,
+            509,
+            599,
+            600,
+            u32::MAX,
+        ] {
+            assert!(matches!(HttpCode::from(value), HttpCode::Unknown(v) if v == value));
         }
     }
-}
 
-impl HttpCodeClass {
-    /// Returns `true` if the class is Informational
-    pub fn is_informational(&self) -> bool {
-        matches!(self, HttpCodeClass::Informational)
+    #[test]
+    fn commonly_confused_with() {
+        assert!(HttpCode::Unauthorized
+            .commonly_confused_with()
+            .contains(&HttpCode::Forbidden));
+        assert!(HttpCode::Forbidden
+            .commonly_confused_with()
+            .contains(&HttpCode::Unauthorized));
+        assert_eq!(HttpCode::Found.commonly_confused_with().len(), 3);
+        assert!(HttpCode::Ok.commonly_confused_with().is_empty());
     }
 
-    /// Returns `true` if the class is Successful
-    pub fn is_successful(&self) -> bool {
-        matches!(self, HttpCodeClass::Successful)
+    #[test]
+    fn valid_trace_response() {
+        assert!(HttpCode::Ok.valid_trace_response());
+        assert!(HttpCode::MethodNotAllowed.valid_trace_response());
+        assert!(!HttpCode::MovedPermanently.valid_trace_response());
+        assert!(!HttpCode::NoContent.valid_trace_response());
     }
 
-    /// Returns `true` if the class is Redirection
-    pub fn is_redirection(&self) -> bool {
-        matches!(self, HttpCodeClass::Redirection)
+    #[test]
+    fn default_body() {
+        assert_eq!(HttpCode::NotFound.default_body(), "404 Not Found");
+        assert_eq!(
+            HttpCode::InternalServerError.default_body(),
+            "500 Internal Server Error"
+        );
+        assert_eq!(HttpCode::Ok.default_body(), "");
+        assert_eq!(HttpCode::Found.default_body(), "");
+        assert_eq!(HttpCode::None.default_body(), "");
     }
 
-    /// Returns `true` if the class is ClientError
-    pub fn is_client_error(&self) -> bool {
-        matches!(self, HttpCodeClass::ClientError)
+    #[test]
+    fn sort_key() {
+        let mut codes = [
+            HttpCode::Unknown(1000),
+            HttpCode::InternalServerError,
+            HttpCode::NotFound,
+            HttpCode::None,
+            HttpCode::Ok,
+            HttpCode::Unknown(42),
+        ];
+        codes.sort_by_key(HttpCode::sort_key);
+
+        assert_eq!(
+            codes,
+            [
+                HttpCode::None,
+                HttpCode::Ok,
+                HttpCode::NotFound,
+                HttpCode::InternalServerError,
+                HttpCode::Unknown(42),
+                HttpCode::Unknown(1000),
+            ]
+        );
     }
 
-    /// Returns `true` if the class is ServerError
-    pub fn is_server_error(&self) -> bool {
-        matches!(self, HttpCodeClass::ServerError)
+    #[test]
+    fn invalidates_cache() {
+        assert!(HttpCode::Ok.invalidates_cache());
+        assert!(HttpCode::MovedPermanently.invalidates_cache());
+        assert!(!HttpCode::NotFound.invalidates_cache());
+        assert!(!HttpCode::InternalServerError.invalidates_cache());
     }
 
-    /// Returns `true` if the class is Unknown
-    pub fn is_unknown(&self) -> bool {
-        matches!(self, HttpCodeClass::Unknown)
+    #[test]
+    fn must_strip_for_http10() {
+        assert!(HttpCode::Continue.must_strip_for_http10());
+        assert!(HttpCode::EarlyHints.must_strip_for_http10());
+        assert!(!HttpCode::Ok.must_strip_for_http10());
     }
 
-    /// Returns `true` if the class is None
-    pub fn is_none(&self) -> bool {
-        matches!(self, HttpCodeClass::None)
+    #[test]
+    fn retry_after_expected() {
+        assert!(HttpCode::TooManyRequests.retry_after_expected());
+        assert!(HttpCode::ServiceUnavailable.retry_after_expected());
+        assert!(HttpCode::MovedPermanently.retry_after_expected());
+        assert!(HttpCode::PermanentRedirect.retry_after_expected());
+        assert!(!HttpCode::InternalServerError.retry_after_expected());
+        assert!(!HttpCode::Found.retry_after_expected());
     }
-}
 
-#[cfg(test)]
-mod test {
-    use crate::HttpCode;
+    #[test]
+    fn etag_action() {
+        assert_eq!(HttpCode::Ok.etag_action(), EtagAction::Store);
+        assert_eq!(HttpCode::NotModified.etag_action(), EtagAction::Confirm);
+        assert_eq!(HttpCode::NotFound.etag_action(), EtagAction::Ignore);
+    }
 
     #[test]
-    fn uint_to_code() {
-        assert!(matches!(HttpCode::from(307), HttpCode::TemporaryRedirect));
-        assert!(matches!(HttpCode::from(410), HttpCode::Gone));
+    fn retry_with_different_representation() {
+        assert!(HttpCode::UnsupportedMediaType.retry_with_different_representation());
+        assert!(HttpCode::NotAcceptable.retry_with_different_representation());
+        assert!(!HttpCode::ServiceUnavailable.retry_with_different_representation());
+    }
+
+    #[test]
+    fn parse_bytes() {
+        assert_eq!(HttpCode::parse_bytes(b"404"), Ok(HttpCode::NotFound));
+        assert_eq!(HttpCode::parse_bytes(b"599"), Ok(HttpCode::Unknown(599)));
+        assert_eq!(
+            HttpCode::parse_bytes(b"4o4"),
+            Err(ParseHttpCodeError::InvalidDigits)
+        );
+        assert_eq!(
+            HttpCode::parse_bytes(b"40"),
+            Err(ParseHttpCodeError::InvalidDigits)
+        );
+        assert_eq!(
+            HttpCode::parse_bytes(b"4040"),
+            Err(ParseHttpCodeError::InvalidDigits)
+        );
+        assert_eq!(
+            HttpCode::parse_bytes(b" 404"),
+            Err(ParseHttpCodeError::InvalidDigits)
+        );
+    }
+
+    #[test]
+    fn elk_bucket() {
+        assert_eq!(HttpCode::NotFound.elk_bucket(), "4xx");
+        assert_eq!(HttpCode::Continue.elk_bucket(), "1xx");
+        assert_eq!(HttpCode::Unknown(599).elk_bucket(), "5xx");
+        assert_eq!(HttpCode::Unknown(42).elk_bucket(), "other");
+        assert_eq!(HttpCode::None.elk_bucket(), "other");
+    }
+
+    #[test]
+    fn is_delete_success() {
+        assert!(HttpCode::NoContent.is_delete_success());
+        assert!(HttpCode::Accepted.is_delete_success());
+        assert!(HttpCode::Ok.is_delete_success());
+        assert!(!HttpCode::Created.is_delete_success());
+        assert!(!HttpCode::NotFound.is_delete_success());
+    }
+
+    #[test]
+    fn is_connect_rejection() {
+        assert!(HttpCode::Forbidden.is_connect_rejection());
+        assert!(HttpCode::MethodNotAllowed.is_connect_rejection());
+        assert!(HttpCode::BadGateway.is_connect_rejection());
+        assert!(!HttpCode::Ok.is_connect_rejection());
+    }
+
+    #[test]
+    fn syslog_sd() {
+        assert!(HttpCode::NotFound.syslog_sd().contains("code=\"404\""));
+        assert_eq!(
+            HttpCode::NotFound.syslog_sd(),
+            "[http@32473 code=\"404\" class=\"4xx\"]"
+        );
+        assert_eq!(
+            HttpCode::None.syslog_sd(),
+            "[http@32473 code=\"0\" class=\"other\"]"
+        );
+    }
+
+    #[test]
+    fn method_problem() {
+        assert_eq!(
+            HttpCode::MethodNotAllowed.method_problem(),
+            Some(MethodProblem::NotAllowed)
+        );
+        assert_eq!(
+            HttpCode::NotImplemented.method_problem(),
+            Some(MethodProblem::NotImplemented)
+        );
+        assert_eq!(HttpCode::BadRequest.method_problem(), None);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_names() {
+        assert_eq!(u32::from(HttpCode::ProxyAuthentificationRequired), 407);
+        assert_eq!(u32::from(HttpCode::ProxyAuthenticationRequired), 407);
+        assert_eq!(u32::from(HttpCode::NetworkAuthetificationRequired), 511);
+        assert_eq!(u32::from(HttpCode::NetworkAuthenticationRequired), 511);
+        assert_eq!(HttpCode::from(407), HttpCode::ProxyAuthentificationRequired);
         assert!(matches!(
-            HttpCode::from(451),
-            HttpCode::UnavailableForLegalReasons
+            HttpCode::from(511),
+            HttpCode::NetworkAuthetificationRequired
         ));
-        assert!(matches!(HttpCode::from(504), HttpCode::GatewayTimeout));
-        assert!(matches!(HttpCode::from(999), HttpCode::Unknown(999)));
     }
 
     #[test]
-    fn option_to_code() {
-        let none: Option<u32> = None;
+    fn generic() {
+        assert_eq!(
+            HttpCode::generic(HttpCodeClass::Informational),
+            HttpCode::Continue
+        );
+        assert_eq!(HttpCode::generic(HttpCodeClass::Successful), HttpCode::Ok);
+        assert_eq!(
+            HttpCode::generic(HttpCodeClass::Redirection),
+            HttpCode::MultipleChoices
+        );
+        assert_eq!(
+            HttpCode::generic(HttpCodeClass::ClientError),
+            HttpCode::BadRequest
+        );
+        assert_eq!(
+            HttpCode::generic(HttpCodeClass::ServerError),
+            HttpCode::InternalServerError
+        );
+        assert_eq!(HttpCode::generic(HttpCodeClass::None), HttpCode::None);
+        assert_eq!(HttpCode::generic(HttpCodeClass::Unknown), HttpCode::None);
+    }
 
-        assert!(matches!(HttpCode::from(Some(103)), HttpCode::EarlyHints));
-        assert!(matches!(HttpCode::from(Some(200)), HttpCode::Ok));
-        assert!(matches!(HttpCode::from(Some(226)), HttpCode::ImUsed));
-        assert!(matches!(HttpCode::from(Some(302)), HttpCode::Found));
-        assert!(matches!(HttpCode::from(none), HttpCode::None));
+    #[test]
+    fn redirect_drops_body() {
+        assert!(HttpCode::SeeOther.redirect_drops_body());
+        assert!(!HttpCode::TemporaryRedirect.redirect_drops_body());
+        assert!(!HttpCode::PermanentRedirect.redirect_drops_body());
     }
 
     #[test]
-    fn code_to_uint() {
-        assert_eq!(u32::from(HttpCode::SwitchingProtocols), 101);
-        assert_eq!(u32::from(HttpCode::NoContent), 204);
-        assert_eq!(u32::from(HttpCode::MultipleChoices), 300);
-        assert_eq!(u32::from(HttpCode::NotFound), 404);
-        assert_eq!(u32::from(HttpCode::RangeNotSatisfiable), 416);
+    fn aria_description() {
+        assert!(HttpCode::NotFound
+            .aria_description()
+            .contains("client error"));
+        assert_eq!(
+            HttpCode::NotFound.aria_description(),
+            "HTTP status 404, Not Found, a client error"
+        );
+        assert_eq!(
+            HttpCode::Unknown(599).aria_description(),
+            "HTTP status 599, an unknown status"
+        );
+        assert_eq!(HttpCode::None.aria_description(), "No HTTP status");
+    }
+
+    #[test]
+    fn class_codes() {
+        let successful = if cfg!(feature = "webdav") { 10 } else { 8 };
+
+        assert_eq!(HttpCodeClass::Successful.codes().count(), successful);
+        assert_eq!(
+            HttpCodeClass::Redirection.codes().collect::<Vec<_>>(),
+            [
+                HttpCode::MultipleChoices,
+                HttpCode::MovedPermanently,
+                HttpCode::Found,
+                HttpCode::SeeOther,
+                HttpCode::NotModified,
+                HttpCode::TemporaryRedirect,
+                HttpCode::PermanentRedirect,
+            ]
+        );
+        assert_eq!(HttpCodeClass::None.codes().count(), 0);
+        assert_eq!(HttpCodeClass::Unknown.codes().count(), 0);
+        assert!(HttpCode::all().all(|code| code.is_standard()));
+    }
+
+    #[test]
+    fn as_str() {
+        assert_eq!(HttpCode::ImATeapot.as_str(), "I'm a teapot");
+        assert_eq!(HttpCode::Ok.as_str(), "OK");
+        assert_eq!(HttpCode::None.as_str(), "");
+        assert_eq!(HttpCode::Unknown(599).as_str(), "");
+    }
+
+    #[test]
+    #[cfg(feature = "webdav")]
+    fn valid_in_multistatus() {
+        assert!(HttpCode::NotFound.valid_in_multistatus());
+        assert!(HttpCode::Locked.valid_in_multistatus());
+        assert!(!HttpCode::MovedPermanently.valid_in_multistatus());
+        assert!(!HttpCode::Continue.valid_in_multistatus());
+    }
+
+    #[test]
+    fn cache_metric() {
+        assert_eq!(HttpCode::NotModified.cache_metric(), CacheMetric::Hit);
+        assert_eq!(HttpCode::Ok.cache_metric(), CacheMetric::Miss);
+        assert_eq!(HttpCode::PartialContent.cache_metric(), CacheMetric::Miss);
+        assert_eq!(
+            HttpCode::NotFound.cache_metric(),
+            CacheMetric::NotApplicable
+        );
+    }
+
+    #[test]
+    fn should_page_oncall() {
+        assert!(HttpCode::InternalServerError.should_page_oncall());
+        assert!(HttpCode::BadGateway.should_page_oncall());
+        assert!(!HttpCode::NotImplemented.should_page_oncall());
+        assert!(!HttpCode::NotFound.should_page_oncall());
+    }
+
+    #[test]
+    fn is_interim() {
+        assert!(HttpCode::Continue.is_interim());
+        assert!(HttpCode::EarlyHints.is_interim());
+        assert!(!HttpCode::Ok.is_interim());
+        assert!(!HttpCode::None.is_interim());
+    }
+
+    #[test]
+    fn closest_known() {
+        assert_eq!(HttpCode::Unknown(450).closest_known(), HttpCode::BadRequest);
+        assert_eq!(
+            HttpCode::Unknown(599).closest_known(),
+            HttpCode::InternalServerError
+        );
+        assert_eq!(HttpCode::Unknown(42).closest_known(), HttpCode::Unknown(42));
+        assert_eq!(HttpCode::NotFound.closest_known(), HttpCode::NotFound);
+        assert_eq!(HttpCode::None.closest_known(), HttpCode::None);
+    }
+
+    #[test]
+    fn known_codes() {
+        let mut variants = 53;
+
+        if cfg!(feature = "nginx") {
+            variants += 2;
+        }
+
+        if cfg!(feature = "webdav") {
+            variants += 8;
+        }
+
+        assert_eq!(HttpCode::KNOWN_CODES.len(), variants);
+        assert!(HttpCode::KNOWN_CODES.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(HttpCode::KNOWN_CODES.len(), HttpCode::all().count());
+    }
+
+    #[test]
+    fn matches_class() {
+        assert!(HttpCode::NotFound.matches_class(HttpCodeClass::ClientError));
+        assert!(HttpCode::Unknown(599).matches_class(HttpCodeClass::Unknown));
+        assert!(HttpCode::None.matches_class(HttpCodeClass::None));
+        assert!(!HttpCode::NotFound.matches_class(HttpCodeClass::ServerError));
+        assert!(!HttpCode::Unknown(599).matches_class(HttpCodeClass::ServerError));
+    }
+
+    #[test]
+    fn class_from_str() {
+        assert_eq!("client-error".parse(), Ok(HttpCodeClass::ClientError));
+        assert_eq!("Client Error".parse(), Ok(HttpCodeClass::ClientError));
+        assert_eq!("client_error".parse(), Ok(HttpCodeClass::ClientError));
+        assert_eq!("ServerError".parse(), Ok(HttpCodeClass::ServerError));
+        assert_eq!("4".parse(), Ok(HttpCodeClass::ClientError));
+        assert_eq!("4xx".parse(), Ok(HttpCodeClass::ClientError));
+        assert_eq!("2XX".parse(), Ok(HttpCodeClass::Successful));
+        assert_eq!(
+            "6xx".parse::<HttpCodeClass>(),
+            Err(ParseHttpCodeError::UnknownClass)
+        );
+        assert_eq!(
+            "client".parse::<HttpCodeClass>(),
+            Err(ParseHttpCodeError::UnknownClass)
+        );
+    }
+
+    #[test]
+    fn classify_many() {
+        let codes: Vec<u32> = (0..1000).collect();
+        let expected: Vec<HttpCodeClass> = codes
+            .iter()
+            .map(|value| HttpCodeClass::from(HttpCode::from(*value)))
+            .collect();
+
+        assert_eq!(HttpCode::classify_many(&codes), expected);
+        assert!(HttpCode::classify_many(&[]).is_empty());
+    }
+
+    #[test]
+    fn redirect_permanence() {
+        for code in [HttpCode::MovedPermanently, HttpCode::PermanentRedirect] {
+            assert!(code.is_permanent_redirect());
+            assert!(!code.is_temporary_redirect());
+        }
+
+        for code in [
+            HttpCode::Found,
+            HttpCode::SeeOther,
+            HttpCode::TemporaryRedirect,
+        ] {
+            assert!(code.is_temporary_redirect());
+            assert!(!code.is_permanent_redirect());
+        }
+
+        for code in [
+            HttpCode::MultipleChoices,
+            HttpCode::NotModified,
+            HttpCode::Ok,
+            HttpCode::None,
+        ] {
+            assert!(!code.is_permanent_redirect());
+            assert!(!code.is_temporary_redirect());
+        }
+    }
+
+    #[test]
+    fn category_emoji() {
+        assert_eq!(HttpCode::Continue.category_emoji(), "\u{2139}\u{fe0f}");
+        assert_eq!(HttpCode::Ok.category_emoji(), "\u{2705}");
+        assert_eq!(HttpCode::Found.category_emoji(), "\u{21aa}\u{fe0f}");
+        assert_eq!(HttpCode::NotFound.category_emoji(), "\u{26a0}\u{fe0f}");
+        assert_eq!(HttpCode::BadGateway.category_emoji(), "\u{1f4a5}");
+        assert_eq!(HttpCode::Unknown(599).category_emoji(), "\u{2753}");
+        assert_eq!(HttpCode::None.category_emoji(), "\u{2753}");
+    }
+
+    #[test]
+    fn try_from_bytes() {
+        assert_eq!(HttpCode::try_from(&b"404"[..]), Ok(HttpCode::NotFound));
+        assert_eq!(HttpCode::try_from(&b"599"[..]), Ok(HttpCode::Unknown(599)));
+        assert_eq!(
+            HttpCode::try_from(&b"abc"[..]),
+            Err(ParseHttpCodeError::InvalidDigits)
+        );
+        assert_eq!(
+            HttpCode::try_from(&b"4040"[..]),
+            Err(ParseHttpCodeError::InvalidDigits)
+        );
+    }
+
+    #[test]
+    fn class_ordering() {
+        let mut classes = vec![
+            HttpCodeClass::ServerError,
+            HttpCodeClass::Unknown,
+            HttpCodeClass::Successful,
+            HttpCodeClass::None,
+            HttpCodeClass::ClientError,
+            HttpCodeClass::Informational,
+            HttpCodeClass::Redirection,
+        ];
+        classes.sort();
+
+        assert_eq!(
+            classes,
+            vec![
+                HttpCodeClass::None,
+                HttpCodeClass::Informational,
+                HttpCodeClass::Successful,
+                HttpCodeClass::Redirection,
+                HttpCodeClass::ClientError,
+                HttpCodeClass::ServerError,
+                HttpCodeClass::Unknown,
+            ]
+        );
+    }
+
+    #[test]
+    fn requires_authentication() {
+        assert!(HttpCode::Unauthorized.requires_authentication());
+        assert!(HttpCode::ProxyAuthenticationRequired.requires_authentication());
+        assert!(HttpCode::NetworkAuthenticationRequired.requires_authentication());
+        assert!(!HttpCode::Forbidden.requires_authentication());
+        assert!(!HttpCode::Ok.requires_authentication());
+    }
+
+    #[test]
+    fn class_to_u8() {
+        assert_eq!(u8::from(HttpCodeClass::Informational), 1);
+        assert_eq!(u8::from(HttpCodeClass::Successful), 2);
+        assert_eq!(u8::from(HttpCodeClass::Redirection), 3);
+        assert_eq!(u8::from(HttpCodeClass::ClientError), 4);
+        assert_eq!(u8::from(HttpCodeClass::ServerError), 5);
+        assert_eq!(u8::from(HttpCodeClass::None), 0);
+        assert_eq!(u8::from(HttpCodeClass::Unknown), 0);
+    }
+
+    #[test]
+    fn status_line() {
+        assert_eq!(HttpCode::Ok.status_line("HTTP/1.1"), "HTTP/1.1 200 OK");
+        assert_eq!(
+            HttpCode::NotFound.status_line("HTTP/1.0"),
+            "HTTP/1.0 404 Not Found"
+        );
+        assert_eq!(
+            HttpCode::Unknown(599).status_line("HTTP/1.1"),
+            "HTTP/1.1 599"
+        );
+        assert_eq!(HttpCode::None.status_line("HTTP/1.1"), "");
+    }
+
+    #[test]
+    fn const_code() {
+        const NOT_FOUND: u32 = HttpCode::NotFound.code();
+        const UNKNOWN: u32 = HttpCode::Unknown(599).code();
+        const NONE: u32 = HttpCode::None.code();
+
+        assert_eq!(NOT_FOUND, 404);
+        assert_eq!(UNKNOWN, 599);
+        assert_eq!(NONE, 0);
+    }
+
+    #[test]
+    fn describe() {
+        let info = HttpCode::NotFound.describe();
+
+        assert_eq!(info.code, 404);
+        assert_eq!(info.phrase, HttpCode::NotFound.reason_phrase());
+        assert_eq!(info.class, HttpCodeClass::ClientError);
+        assert_eq!(info.description, HttpCode::NotFound.description());
+        assert_eq!(info.rfc, HttpCode::NotFound.rfc_reference());
+        assert_eq!(HttpCode::None.describe().code, 0);
+    }
+
+    #[test]
+    fn code_to_signed() {
+        assert_eq!(i32::from(HttpCode::NotFound), 404);
+        assert_eq!(i32::from(HttpCode::None), 0);
+        assert_eq!(i32::from(HttpCode::Unknown(u32::MAX)), i32::MAX);
+        assert_eq!(i64::from(HttpCode::NotFound), 404);
+        assert_eq!(i64::from(HttpCode::Unknown(599)), 599);
+        assert_eq!(i64::from(HttpCode::None), 0);
+    }
+
+    #[test]
+    fn is_final() {
+        assert!(!HttpCode::Continue.is_final());
+        assert!(HttpCode::Ok.is_final());
+        assert!(HttpCode::ServiceUnavailable.is_final());
+        assert!(!HttpCode::None.is_final());
+        assert!(!HttpCode::Unknown(599).is_final());
+    }
+
+    #[test]
+    fn try_from_f64() {
+        assert_eq!(HttpCode::try_from_f64(404.0), Ok(HttpCode::NotFound));
+        assert_eq!(HttpCode::try_from_f64(599.0), Ok(HttpCode::Unknown(599)));
+        assert_eq!(
+            HttpCode::try_from_f64(404.5),
+            Err(ParseHttpCodeError::InvalidNumber)
+        );
+        assert_eq!(
+            HttpCode::try_from_f64(-1.0),
+            Err(ParseHttpCodeError::InvalidNumber)
+        );
+        assert_eq!(
+            HttpCode::try_from_f64(f64::NAN),
+            Err(ParseHttpCodeError::InvalidNumber)
+        );
+        assert_eq!(
+            HttpCode::try_from_f64(f64::INFINITY),
+            Err(ParseHttpCodeError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn group_counts() {
+        let codes = [
+            HttpCode::None,
+            HttpCode::Continue,
+            HttpCode::Ok,
+            HttpCode::Created,
+            HttpCode::Found,
+            HttpCode::NotFound,
+            HttpCode::Forbidden,
+            HttpCode::BadRequest,
+            HttpCode::BadGateway,
+            HttpCode::Unknown(599),
+            HttpCode::Unknown(42),
+        ];
+
+        assert_eq!(HttpCode::group_counts(codes), [1, 1, 2, 1, 3, 1, 2]);
+        assert_eq!(HttpCode::group_counts([]), [0; 7]);
+    }
+
+    #[test]
+    fn classify() {
+        for (value, code) in HttpCode::KNOWN_CODES {
+            let expected = match value / 100 {
+                1 => HttpCodeClass::Informational,
+                2 => HttpCodeClass::Successful,
+                3 => HttpCodeClass::Redirection,
+                4 => HttpCodeClass::ClientError,
+                _ => HttpCodeClass::ServerError,
+            };
+
+            assert_eq!(code.classify(), expected);
+        }
+
+        assert_eq!(HttpCode::Unknown(599).classify(), HttpCodeClass::Unknown);
+        assert_eq!(HttpCode::None.classify(), HttpCodeClass::None);
+    }
+
+    #[test]
+    fn from_reason_phrase() {
+        assert_eq!(
+            HttpCode::from_reason_phrase("Not Found"),
+            Some(HttpCode::NotFound)
+        );
+        assert_eq!(
+            HttpCode::from_reason_phrase("not found"),
+            Some(HttpCode::NotFound)
+        );
+        assert_eq!(
+            HttpCode::from_reason_phrase("I'M A TEAPOT"),
+            Some(HttpCode::ImATeapot)
+        );
+        assert_eq!(HttpCode::from_reason_phrase("Nonexistent"), None);
+        assert_eq!(HttpCode::from_reason_phrase(""), None);
+
+        for code in HttpCode::all() {
+            assert_eq!(
+                HttpCode::from_reason_phrase(code.reason_phrase()),
+                Some(code)
+            );
+        }
+    }
+
+    #[test]
+    fn metric_label() {
+        const LABEL: &str = HttpCode::NotFound.metric_label();
+
+        assert_eq!(LABEL, "4xx");
+        assert_eq!(HttpCode::Continue.metric_label(), "1xx");
+        assert_eq!(HttpCode::Ok.metric_label(), "2xx");
+        assert_eq!(HttpCode::Found.metric_label(), "3xx");
+        assert_eq!(HttpCode::BadGateway.metric_label(), "5xx");
+        assert_eq!(HttpCode::Unknown(599).metric_label(), "unknown");
+        assert_eq!(HttpCode::None.metric_label(), "none");
+    }
+
+    #[test]
+    fn representative_code() {
+        assert_eq!(
+            HttpCodeClass::Informational.representative_code(),
+            Some(100)
+        );
+        assert_eq!(HttpCodeClass::Successful.representative_code(), Some(200));
+        assert_eq!(HttpCodeClass::Redirection.representative_code(), Some(300));
+        assert_eq!(HttpCodeClass::ClientError.representative_code(), Some(400));
+        assert_eq!(HttpCodeClass::ServerError.representative_code(), Some(500));
+        assert_eq!(HttpCodeClass::None.representative_code(), None);
+        assert_eq!(HttpCodeClass::Unknown.representative_code(), None);
+
+        for class in [HttpCodeClass::ClientError, HttpCodeClass::ServerError] {
+            assert_eq!(
+                class.representative_code(),
+                Some(HttpCode::generic(class).code())
+            );
+        }
+    }
+
+    #[test]
+    fn normalized() {
+        assert_eq!(HttpCode::Unknown(99).normalized(), HttpCode::None);
+        assert_eq!(HttpCode::Unknown(1000).normalized(), HttpCode::None);
+        assert_eq!(HttpCode::Unknown(419).normalized(), HttpCode::Unknown(419));
+        assert_eq!(HttpCode::Unknown(599).normalized(), HttpCode::Unknown(599));
+        assert_eq!(HttpCode::ImATeapot.normalized(), HttpCode::ImATeapot);
+        assert_eq!(HttpCode::None.normalized(), HttpCode::None);
+    }
+
+    #[test]
+    fn code_eq_class() {
+        assert!(HttpCode::NotFound == HttpCodeClass::ClientError);
+        assert!(HttpCode::NotFound != HttpCodeClass::ServerError);
+        assert!(HttpCode::Unknown(599) == HttpCodeClass::Unknown);
+        assert!(HttpCode::None == HttpCodeClass::None);
+    }
+
+    #[test]
+    fn class_code_slices() {
+        for (codes, class) in [
+            (HttpCode::INFORMATIONAL_CODES, HttpCodeClass::Informational),
+            (HttpCode::SUCCESSFUL_CODES, HttpCodeClass::Successful),
+            (HttpCode::REDIRECTION_CODES, HttpCodeClass::Redirection),
+            (HttpCode::CLIENT_ERROR_CODES, HttpCodeClass::ClientError),
+            (HttpCode::SERVER_ERROR_CODES, HttpCodeClass::ServerError),
+        ] {
+            assert!(codes.iter().all(|code| code.to_class() == class));
+            assert!(codes.iter().copied().eq(class.codes()));
+        }
+    }
+
+    #[test]
+    fn code_to_io_error() {
+        use std::io::{Error, ErrorKind};
+
+        assert_eq!(
+            Error::from(HttpCode::RequestTimeout).kind(),
+            ErrorKind::TimedOut
+        );
+        assert_eq!(
+            Error::from(HttpCode::GatewayTimeout).kind(),
+            ErrorKind::TimedOut
+        );
+        assert_eq!(
+            Error::from(HttpCode::Unauthorized).kind(),
+            ErrorKind::PermissionDenied
+        );
+        assert_eq!(
+            Error::from(HttpCode::Forbidden).kind(),
+            ErrorKind::PermissionDenied
+        );
+        assert_eq!(Error::from(HttpCode::NotFound).kind(), ErrorKind::NotFound);
+        assert_eq!(Error::from(HttpCode::BadGateway).kind(), ErrorKind::Other);
+        assert_eq!(Error::from(HttpCode::NotFound).to_string(), "404 Not Found");
+    }
+
+    #[test]
+    fn ensure_success() {
+        assert_eq!(HttpCode::Ok.ensure_success(), Ok(HttpCode::Ok));
+        assert_eq!(HttpCode::NotFound.ensure_success(), Err(HttpCode::NotFound));
+        assert_eq!(HttpCode::None.ensure_success(), Err(HttpCode::None));
+        assert_eq!(
+            HttpCode::Unknown(299).ensure_success(),
+            Err(HttpCode::Unknown(299))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "webdav")]
+    fn is_webdav() {
+        assert!(HttpCode::MultiStatus.is_webdav());
+        assert!(HttpCode::Locked.is_webdav());
+        assert!(HttpCode::LoopDetected.is_webdav());
+        assert!(!HttpCode::NotFound.is_webdav());
+        assert!(!HttpCode::ImUsed.is_webdav());
+    }
+
+    #[test]
+    fn next_known() {
+        let mut code = HttpCode::NotFound;
+        let mut walked = Vec::new();
+
+        for _ in 0..3 {
+            code = code.next_known().unwrap();
+            walked.push(code);
+        }
+
+        assert_eq!(
+            walked,
+            [
+                HttpCode::MethodNotAllowed,
+                HttpCode::NotAcceptable,
+                HttpCode::ProxyAuthenticationRequired
+            ]
+        );
+        assert_eq!(
+            HttpCode::Unknown(419).next_known(),
+            Some(HttpCode::MisdirectedRequest)
+        );
+        assert_eq!(HttpCode::None.next_known(), Some(HttpCode::Continue));
+        assert_eq!(HttpCode::NetworkAuthenticationRequired.next_known(), None);
+    }
+
+    #[test]
+    fn prev_known() {
+        let mut code = HttpCode::Ok;
+        let mut walked = Vec::new();
+
+        while let Some(prev) = code.prev_known() {
+            code = prev;
+            walked.push(code);
+        }
+
+        assert_eq!(walked.first(), Some(&HttpCode::EarlyHints));
+        assert_eq!(walked.last(), Some(&HttpCode::Continue));
+        assert_eq!(walked.len(), HttpCodeClass::Informational.codes().count());
+        assert_eq!(
+            HttpCode::Unknown(599).prev_known(),
+            Some(HttpCode::NetworkAuthenticationRequired)
+        );
+        assert_eq!(HttpCode::None.prev_known(), None);
+    }
+
+    #[test]
+    fn from_env() {
+        std::env::set_var("HTTP_CODEX_TEST_NUMBER", "503");
+        std::env::set_var("HTTP_CODEX_TEST_NAME", "not-found");
+        std::env::set_var("HTTP_CODEX_TEST_INVALID", "nope");
+
+        assert_eq!(
+            HttpCode::from_env("HTTP_CODEX_TEST_NUMBER"),
+            Some(HttpCode::ServiceUnavailable)
+        );
+        assert_eq!(
+            HttpCode::from_env("HTTP_CODEX_TEST_NAME"),
+            Some(HttpCode::NotFound)
+        );
+        assert_eq!(HttpCode::from_env("HTTP_CODEX_TEST_INVALID"), None);
+        assert_eq!(HttpCode::from_env("HTTP_CODEX_TEST_UNSET"), None);
+    }
+
+    #[test]
+    fn is_retryable() {
+        assert!(HttpCode::ServiceUnavailable.is_retryable());
+        assert!(HttpCode::TooManyRequests.is_retryable());
+        assert!(!HttpCode::InternalServerError.is_retryable());
+        assert!(!HttpCode::Ok.is_retryable());
+    }
+
+    #[test]
+    fn display_alternate() {
+        assert_eq!(format!("{}", HttpCode::NotFound), "404 Not Found");
+        assert_eq!(format!("{:#}", HttpCode::NotFound), "404");
+        assert_eq!(format!("{}", HttpCode::Unknown(599)), "599");
+        assert_eq!(format!("{:#}", HttpCode::Unknown(599)), "599");
+        assert_eq!(format!("{}", HttpCode::None), "");
+        assert_eq!(format!("{:#}", HttpCode::None), "");
+    }
+
+    #[test]
+    fn known_count() {
+        assert_eq!(HttpCode::all().count(), HttpCode::KNOWN_COUNT);
+        assert_eq!(HttpCode::KNOWN_CODES.len(), HttpCode::KNOWN_COUNT);
+    }
+
+    #[test]
+    fn round_trip() {
+        for code in HttpCode::all() {
+            assert_eq!(HttpCode::from(u32::from(code)), code);
+        }
+
+        for (value, code) in HttpCode::KNOWN_CODES {
+            assert_eq!(u32::from(HttpCode::from(*value)), *value);
+            assert_eq!(HttpCode::from(*value), *code);
+        }
+    }
+
+    #[test]
+    fn is_negotiation_failure() {
+        assert!(HttpCode::NotAcceptable.is_negotiation_failure());
+        assert!(HttpCode::UnsupportedMediaType.is_negotiation_failure());
+        assert!(!HttpCode::BadRequest.is_negotiation_failure());
     }
 }
@@ -0,0 +1,144 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::HttpCode;
+use std::borrow::Cow;
+
+impl HttpCode {
+    /// Returns the reason phrase of the code in the given language
+    ///
+    /// The language is a tag like `fr` or `fr-FR`, only its primary subtag is used. Supported
+    /// languages are English (`en`) and French (`fr`), any other one falls back to English.
+    /// `None` and `Unknown` codes give an empty string.
+    pub fn reason_phrase_localized(&self, lang: &str) -> Cow<'static, str> {
+        let primary = lang.split(['-', '_']).next().unwrap_or_default();
+
+        let phrase = match primary.to_ascii_lowercase().as_str() {
+            "fr" => french(self),
+            _ => self.reason_phrase(),
+        };
+
+        Cow::Borrowed(phrase)
+    }
+}
+
+/// Returns the French reason phrase of a code
+fn french(code: &HttpCode) -> &'static str {
+    match code {
+        HttpCode::Continue => "Continuer",
+        HttpCode::SwitchingProtocols => "Changement de protocole",
+        #[cfg(feature = "webdav")]
+        HttpCode::Processing => "Traitement en cours",
+        HttpCode::EarlyHints => "Indications préliminaires",
+        HttpCode::Ok => "OK",
+        HttpCode::Created => "Créé",
+        HttpCode::Accepted => "Accepté",
+        HttpCode::NonAuthoritativeInformation => "Information non certifiée",
+        HttpCode::NoContent => "Pas de contenu",
+        HttpCode::ResetContent => "Contenu réinitialisé",
+        HttpCode::PartialContent => "Contenu partiel",
+        #[cfg(feature = "webdav")]
+        HttpCode::MultiStatus => "Multi-statut",
+        #[cfg(feature = "webdav")]
+        HttpCode::AlreadyReported => "Déjà rapporté",
+        HttpCode::ImUsed => "IM utilisé",
+        HttpCode::MultipleChoices => "Choix multiples",
+        HttpCode::MovedPermanently => "Déplacé de façon permanente",
+        HttpCode::Found => "Trouvé",
+        HttpCode::SeeOther => "Voir ailleurs",
+        HttpCode::NotModified => "Non modifié",
+        HttpCode::TemporaryRedirect => "Redirection temporaire",
+        HttpCode::PermanentRedirect => "Redirection permanente",
+        HttpCode::BadRequest => "Mauvaise requête",
+        HttpCode::Unauthorized => "Non autorisé",
+        HttpCode::PaymentRequired => "Paiement requis",
+        HttpCode::Forbidden => "Interdit",
+        HttpCode::NotFound => "Non trouvé",
+        HttpCode::MethodNotAllowed => "Méthode non autorisée",
+        HttpCode::NotAcceptable => "Non acceptable",
+        HttpCode::ProxyAuthenticationRequired => "Authentification proxy requise",
+        HttpCode::RequestTimeout => "Délai d'attente de la requête dépassé",
+        HttpCode::Conflict => "Conflit",
+        HttpCode::Gone => "Disparu",
+        HttpCode::LengthRequired => "Longueur requise",
+        HttpCode::PreconditionFailed => "Précondition échouée",
+        HttpCode::PayloadTooLarge => "Charge utile trop volumineuse",
+        HttpCode::UriTooLong => "URI trop longue",
+        HttpCode::UnsupportedMediaType => "Type de média non supporté",
+        HttpCode::RangeNotSatisfiable => "Plage non satisfaisable",
+        HttpCode::ExpectationFailed => "Attente non satisfaite",
+        HttpCode::ImATeapot => "Je suis une théière",
+        HttpCode::MisdirectedRequest => "Requête mal dirigée",
+        #[cfg(feature = "webdav")]
+        HttpCode::UnprocessableContent => "Contenu non traitable",
+        #[cfg(feature = "webdav")]
+        HttpCode::Locked => "Verrouillé",
+        #[cfg(feature = "webdav")]
+        HttpCode::FailedDependency => "Échec de dépendance",
+        HttpCode::TooEarly => "Trop tôt",
+        HttpCode::UpgradeRequired => "Mise à niveau requise",
+        HttpCode::PreconditionRequired => "Précondition requise",
+        HttpCode::TooManyRequests => "Trop de requêtes",
+        HttpCode::RequestHeaderFieldsTooLarge => "Champs d'en-tête de requête trop grands",
+        #[cfg(feature = "nginx")]
+        HttpCode::NoResponse => "Pas de réponse",
+        HttpCode::UnavailableForLegalReasons => "Indisponible pour raisons légales",
+        #[cfg(feature = "nginx")]
+        HttpCode::ClientClosedRequest => "Requête fermée par le client",
+        HttpCode::InternalServerError => "Erreur interne du serveur",
+        HttpCode::NotImplemented => "Non implémenté",
+        HttpCode::BadGateway => "Mauvaise passerelle",
+        HttpCode::ServiceUnavailable => "Service indisponible",
+        HttpCode::GatewayTimeout => "Délai d'attente de la passerelle dépassé",
+        HttpCode::HttpVersionNotSupported => "Version HTTP non supportée",
+        HttpCode::VariantAlsoNegotiates => "La variante négocie aussi",
+        #[cfg(feature = "webdav")]
+        HttpCode::InsufficientStorage => "Espace de stockage insuffisant",
+        #[cfg(feature = "webdav")]
+        HttpCode::LoopDetected => "Boucle détectée",
+        HttpCode::NotExtended => "Non étendu",
+        HttpCode::NetworkAuthenticationRequired => "Authentification réseau requise",
+        HttpCode::None | HttpCode::Unknown(_) => "",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::HttpCode;
+
+    #[test]
+    fn reason_phrase_localized() {
+        assert_eq!(
+            HttpCode::NotFound.reason_phrase_localized("en"),
+            "Not Found"
+        );
+        assert_eq!(
+            HttpCode::NotFound.reason_phrase_localized("fr"),
+            "Non trouvé"
+        );
+        assert_eq!(
+            HttpCode::InternalServerError.reason_phrase_localized("fr-FR"),
+            "Erreur interne du serveur"
+        );
+        assert_eq!(
+            HttpCode::ImATeapot.reason_phrase_localized("FR"),
+            "Je suis une théière"
+        );
+        assert_eq!(HttpCode::Ok.reason_phrase_localized("de"), "OK");
+        assert_eq!(HttpCode::Unknown(599).reason_phrase_localized("fr"), "");
+    }
+}
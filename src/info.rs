@@ -0,0 +1,37 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::HttpCodeClass;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+/// Everything known about a code, as given by [`HttpCode::describe`](crate::HttpCode::describe)
+pub struct CodeInfo {
+    /// The numeric value, `0` for `None`
+    pub code: u32,
+
+    /// The reason phrase, like `Not Found`
+    pub phrase: &'static str,
+
+    /// The class of the code
+    pub class: HttpCodeClass,
+
+    /// The explanation of the code
+    pub description: &'static str,
+
+    /// The specification defining the code, like `RFC 9110`
+    pub rfc: Option<&'static str>,
+}
@@ -23,3 +23,90 @@
 mod http_code;
 
 pub use http_code::*;
+
+/// The cache related types
+mod cache;
+
+pub use cache::*;
+
+/// Numeric constants of the codes
+pub mod codes;
+
+/// The parsing errors
+mod error;
+
+pub use error::*;
+
+/// The gRPC status codes
+mod grpc;
+
+pub use grpc::*;
+
+/// The code metadata
+mod info;
+
+pub use info::*;
+
+/// The request method related types
+mod method;
+
+pub use method::*;
+
+/// The macros
+mod macros;
+
+/// The log severities
+mod severity;
+
+pub use severity::*;
+
+/// Actix Web integration
+#[cfg(feature = "actix")]
+mod actix;
+
+/// Arbitrary integration
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+/// Axum integration
+#[cfg(feature = "axum")]
+mod axum;
+
+/// Clap integration
+#[cfg(feature = "clap")]
+mod clap;
+
+/// HTTP crate integration
+#[cfg(feature = "http")]
+mod http;
+
+/// Hyper integration
+#[cfg(feature = "hyper")]
+mod hyper;
+
+/// Localized reason phrases
+#[cfg(feature = "i18n")]
+mod i18n;
+
+/// Proptest integration
+#[cfg(feature = "proptest")]
+mod proptest;
+
+/// Reqwest integration
+#[cfg(feature = "reqwest")]
+mod reqwest;
+
+/// Schemars integration
+#[cfg(feature = "schemars")]
+mod schemars;
+
+/// Serde integration
+#[cfg(feature = "serde")]
+mod serde;
+
+/// Tower integration
+#[cfg(feature = "tower")]
+mod tower;
+
+#[cfg(feature = "tower")]
+pub use tower::*;
@@ -0,0 +1,323 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+/// Builds an [`HttpCode`](crate::HttpCode) from a numeric literal at compile time
+///
+/// The literal is resolved by the macro itself, so the result can be used in const context and
+/// an unknown code is a compilation error instead of an `HttpCode::Unknown`.
+///
+/// ```rust
+/// use http_codex::{http_code, HttpCode};
+///
+/// const NOT_FOUND: HttpCode = http_code!(404);
+/// assert_eq!(NOT_FOUND, HttpCode::NotFound);
+/// ```
+///
+/// ```compile_fail
+/// let code = http_codex::http_code!(599);
+/// ```
+///
+/// Codes enabled by features, like the nginx ones, only compile when the feature is enabled,
+/// otherwise the error names the missing feature.
+#[macro_export]
+macro_rules! http_code {
+    (100) => {
+        $crate::HttpCode::Continue
+    };
+    (101) => {
+        $crate::HttpCode::SwitchingProtocols
+    };
+    (102) => {
+        $crate::__http_code_webdav!(102, Processing)
+    };
+    (103) => {
+        $crate::HttpCode::EarlyHints
+    };
+    (200) => {
+        $crate::HttpCode::Ok
+    };
+    (201) => {
+        $crate::HttpCode::Created
+    };
+    (202) => {
+        $crate::HttpCode::Accepted
+    };
+    (203) => {
+        $crate::HttpCode::NonAuthoritativeInformation
+    };
+    (204) => {
+        $crate::HttpCode::NoContent
+    };
+    (205) => {
+        $crate::HttpCode::ResetContent
+    };
+    (206) => {
+        $crate::HttpCode::PartialContent
+    };
+    (207) => {
+        $crate::__http_code_webdav!(207, MultiStatus)
+    };
+    (208) => {
+        $crate::__http_code_webdav!(208, AlreadyReported)
+    };
+    (226) => {
+        $crate::HttpCode::ImUsed
+    };
+    (300) => {
+        $crate::HttpCode::MultipleChoices
+    };
+    (301) => {
+        $crate::HttpCode::MovedPermanently
+    };
+    (302) => {
+        $crate::HttpCode::Found
+    };
+    (303) => {
+        $crate::HttpCode::SeeOther
+    };
+    (304) => {
+        $crate::HttpCode::NotModified
+    };
+    (307) => {
+        $crate::HttpCode::TemporaryRedirect
+    };
+    (308) => {
+        $crate::HttpCode::PermanentRedirect
+    };
+    (400) => {
+        $crate::HttpCode::BadRequest
+    };
+    (401) => {
+        $crate::HttpCode::Unauthorized
+    };
+    (402) => {
+        $crate::HttpCode::PaymentRequired
+    };
+    (403) => {
+        $crate::HttpCode::Forbidden
+    };
+    (404) => {
+        $crate::HttpCode::NotFound
+    };
+    (405) => {
+        $crate::HttpCode::MethodNotAllowed
+    };
+    (406) => {
+        $crate::HttpCode::NotAcceptable
+    };
+    (407) => {
+        $crate::HttpCode::ProxyAuthenticationRequired
+    };
+    (408) => {
+        $crate::HttpCode::RequestTimeout
+    };
+    (409) => {
+        $crate::HttpCode::Conflict
+    };
+    (410) => {
+        $crate::HttpCode::Gone
+    };
+    (411) => {
+        $crate::HttpCode::LengthRequired
+    };
+    (412) => {
+        $crate::HttpCode::PreconditionFailed
+    };
+    (413) => {
+        $crate::HttpCode::PayloadTooLarge
+    };
+    (414) => {
+        $crate::HttpCode::UriTooLong
+    };
+    (415) => {
+        $crate::HttpCode::UnsupportedMediaType
+    };
+    (416) => {
+        $crate::HttpCode::RangeNotSatisfiable
+    };
+    (417) => {
+        $crate::HttpCode::ExpectationFailed
+    };
+    (418) => {
+        $crate::HttpCode::ImATeapot
+    };
+    (421) => {
+        $crate::HttpCode::MisdirectedRequest
+    };
+    (422) => {
+        $crate::__http_code_webdav!(422, UnprocessableContent)
+    };
+    (423) => {
+        $crate::__http_code_webdav!(423, Locked)
+    };
+    (424) => {
+        $crate::__http_code_webdav!(424, FailedDependency)
+    };
+    (425) => {
+        $crate::HttpCode::TooEarly
+    };
+    (426) => {
+        $crate::HttpCode::UpgradeRequired
+    };
+    (428) => {
+        $crate::HttpCode::PreconditionRequired
+    };
+    (429) => {
+        $crate::HttpCode::TooManyRequests
+    };
+    (431) => {
+        $crate::HttpCode::RequestHeaderFieldsTooLarge
+    };
+    (444) => {
+        $crate::__http_code_nginx!(444, NoResponse)
+    };
+    (451) => {
+        $crate::HttpCode::UnavailableForLegalReasons
+    };
+    (499) => {
+        $crate::__http_code_nginx!(499, ClientClosedRequest)
+    };
+    (500) => {
+        $crate::HttpCode::InternalServerError
+    };
+    (501) => {
+        $crate::HttpCode::NotImplemented
+    };
+    (502) => {
+        $crate::HttpCode::BadGateway
+    };
+    (503) => {
+        $crate::HttpCode::ServiceUnavailable
+    };
+    (504) => {
+        $crate::HttpCode::GatewayTimeout
+    };
+    (505) => {
+        $crate::HttpCode::HttpVersionNotSupported
+    };
+    (506) => {
+        $crate::HttpCode::VariantAlsoNegotiates
+    };
+    (507) => {
+        $crate::__http_code_webdav!(507, InsufficientStorage)
+    };
+    (508) => {
+        $crate::__http_code_webdav!(508, LoopDetected)
+    };
+    (510) => {
+        $crate::HttpCode::NotExtended
+    };
+    (511) => {
+        $crate::HttpCode::NetworkAuthenticationRequired
+    };
+    ($code:literal) => {
+        compile_error!(concat!("unknown HTTP code: ", stringify!($code)))
+    };
+}
+
+/// Resolves a code of the `nginx` feature for [`http_code!`]
+#[doc(hidden)]
+#[cfg(feature = "nginx")]
+#[macro_export]
+macro_rules! __http_code_nginx {
+    ($code:literal, $variant:ident) => {
+        $crate::HttpCode::$variant
+    };
+}
+
+/// Resolves a code of the `nginx` feature for [`http_code!`]
+#[doc(hidden)]
+#[cfg(not(feature = "nginx"))]
+#[macro_export]
+macro_rules! __http_code_nginx {
+    ($code:literal, $variant:ident) => {
+        compile_error!(concat!(
+            "HTTP code ",
+            stringify!($code),
+            " requires the `nginx` feature of http_codex"
+        ))
+    };
+}
+
+/// Resolves a code of the `webdav` feature for [`http_code!`]
+#[doc(hidden)]
+#[cfg(feature = "webdav")]
+#[macro_export]
+macro_rules! __http_code_webdav {
+    ($code:literal, $variant:ident) => {
+        $crate::HttpCode::$variant
+    };
+}
+
+/// Resolves a code of the `webdav` feature for [`http_code!`]
+#[doc(hidden)]
+#[cfg(not(feature = "webdav"))]
+#[macro_export]
+macro_rules! __http_code_webdav {
+    ($code:literal, $variant:ident) => {
+        compile_error!(concat!(
+            "HTTP code ",
+            stringify!($code),
+            " requires the `webdav` feature of http_codex"
+        ))
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::HttpCode;
+
+    #[test]
+    fn http_code_macro() {
+        const TEAPOT: HttpCode = http_code!(418);
+
+        assert_eq!(TEAPOT, HttpCode::ImATeapot);
+        assert_eq!(http_code!(100), HttpCode::Continue);
+        assert_eq!(http_code!(511), HttpCode::NetworkAuthenticationRequired);
+    }
+
+    /// Resolves every given literal with [`http_code!`], paired with its numeric value
+    macro_rules! resolve {
+        ($($code:tt),*) => {
+            vec![$(($code, http_code!($code))),*]
+        };
+    }
+
+    #[test]
+    fn http_code_macro_matches_table() {
+        let mut resolved = resolve!(
+            100, 101, 103, 200, 201, 202, 203, 204, 205, 206, 226, 300, 301, 302, 303, 304, 307,
+            308, 400, 401, 402, 403, 404, 405, 406, 407, 408, 409, 410, 411, 412, 413, 414, 415,
+            416, 417, 418, 421, 425, 426, 428, 429, 431, 451, 500, 501, 502, 503, 504, 505, 506,
+            510, 511
+        );
+
+        #[cfg(feature = "nginx")]
+        resolved.extend(resolve!(444, 499));
+
+        #[cfg(feature = "webdav")]
+        resolved.extend(resolve!(102, 207, 208, 422, 423, 424, 507, 508));
+
+        resolved.sort_by_key(|(value, _)| *value);
+
+        for (value, code) in &resolved {
+            assert_eq!(*code, HttpCode::from(*value));
+        }
+
+        assert_eq!(resolved, HttpCode::KNOWN_CODES);
+    }
+}
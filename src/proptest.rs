@@ -0,0 +1,55 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::HttpCode;
+use ::proptest::prelude::{any, prop_oneof, Just, Strategy};
+use ::proptest::sample::select;
+
+impl HttpCode {
+    /// Returns a proptest strategy generating codes
+    ///
+    /// Most generated codes are standard ones, the others are any numeric code or `None`.
+    pub fn arb() -> impl Strategy<Value = HttpCode> {
+        prop_oneof![
+            8 => select(HttpCode::KNOWN_CODES).prop_map(|(_, code)| code),
+            1 => any::<u32>().prop_map(HttpCode::from),
+            1 => Just(HttpCode::None),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::HttpCode;
+    use ::proptest::{prop_assert_eq, proptest};
+
+    proptest! {
+        #[test]
+        fn round_trip(code in HttpCode::arb()) {
+            if code.is_standard() {
+                prop_assert_eq!(HttpCode::from(u32::from(code)), code);
+            }
+        }
+
+        #[test]
+        fn unknown_keeps_value(code in HttpCode::arb()) {
+            if let HttpCode::Unknown(value) = code {
+                prop_assert_eq!(u32::from(code), value);
+            }
+        }
+    }
+}
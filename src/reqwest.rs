@@ -0,0 +1,41 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::HttpCode;
+use ::reqwest::StatusCode;
+
+impl From<StatusCode> for HttpCode {
+    fn from(value: StatusCode) -> Self {
+        value.as_u16().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::HttpCode;
+    use ::reqwest::StatusCode;
+
+    #[test]
+    fn status_code_to_code() {
+        assert_eq!(HttpCode::from(StatusCode::NOT_FOUND), HttpCode::NotFound);
+        assert_eq!(HttpCode::from(StatusCode::OK), HttpCode::Ok);
+        assert_eq!(
+            HttpCode::from(StatusCode::from_u16(599).unwrap()),
+            HttpCode::Unknown(599)
+        );
+    }
+}
@@ -0,0 +1,62 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::HttpCode;
+use ::schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use std::borrow::Cow;
+
+impl JsonSchema for HttpCode {
+    fn schema_name() -> Cow<'static, str> {
+        "HttpCode".into()
+    }
+
+    /// Describes the code as an integer, listing the known values
+    ///
+    /// Unknown codes are accepted as any other integer and `None` as a null value, which is how
+    /// they are serialized.
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let known: Vec<u32> = HttpCode::KNOWN_CODES.iter().map(|(v, _)| *v).collect();
+
+        json_schema!({
+            "description": "An HTTP status code",
+            "anyOf": [
+                { "type": "integer", "enum": known },
+                { "type": "integer", "minimum": 0 },
+                { "type": "null" }
+            ]
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::HttpCode;
+    use ::schemars::schema_for;
+
+    #[test]
+    fn schema_lists_known_codes() {
+        let schema = schema_for!(HttpCode);
+        let known = schema
+            .get("anyOf")
+            .and_then(|any_of| any_of[0].get("enum"))
+            .and_then(|known| known.as_array())
+            .unwrap();
+
+        assert!(known.contains(&404.into()));
+        assert_eq!(known.len(), HttpCode::KNOWN_CODES.len());
+    }
+}
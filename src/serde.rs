@@ -0,0 +1,160 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::HttpCode;
+use ::serde::de::{Error, Unexpected, Visitor};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Formatter;
+
+impl Serialize for HttpCode {
+    /// Serializes the code as an integer, `None` is serialized as a missing value
+    ///
+    /// Formats that are not human readable always get a plain integer, `0` for `None`, so they
+    /// can be read back without describing their own types.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            HttpCode::None if serializer.is_human_readable() => serializer.serialize_none(),
+            _ => serializer.serialize_u32(self.code()),
+        }
+    }
+}
+
+/// Visitor accepting integers, numeric strings and status strings like `"404 Not Found"`
+struct HttpCodeVisitor;
+
+impl Visitor<'_> for HttpCodeVisitor {
+    type Value = HttpCode;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "an HTTP code as an integer or a string like \"404 Not Found\""
+        )
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        u32::try_from(v)
+            .map(HttpCode::from)
+            .map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        u32::try_from(v)
+            .map(HttpCode::from)
+            .map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+    }
+
+    /// Reads the number at the start of the string, a reason phrase after it must match the one
+    /// of the code when the code is known
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        let invalid = || E::invalid_value(Unexpected::Str(v), &self);
+        let mut words = v.split_whitespace();
+        let number = words.next().ok_or_else(invalid)?;
+
+        if !number.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+
+        let code = number
+            .parse::<u32>()
+            .map(HttpCode::from)
+            .map_err(|_| invalid())?;
+        let phrase = words.collect::<Vec<_>>().join(" ");
+
+        if phrase.is_empty()
+            || matches!(code, HttpCode::Unknown(_))
+            || phrase.eq_ignore_ascii_case(code.reason_phrase())
+        {
+            Ok(code)
+        } else {
+            Err(invalid())
+        }
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(HttpCode::None)
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(HttpCode::None)
+    }
+}
+
+impl<'de> Deserialize<'de> for HttpCode {
+    /// Deserializes a code from an integer (`404`), a numeric string (`"404"`) or a status
+    /// string (`"404 Not Found"`), a missing value gives `HttpCode::None`
+    ///
+    /// Formats that are not human readable only accept the plain integer written by
+    /// [`Serialize`], `0` giving `HttpCode::None`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            return u32::deserialize(deserializer).map(|v| match v {
+                0 => HttpCode::None,
+                v => HttpCode::from(v),
+            });
+        }
+
+        deserializer.deserialize_any(HttpCodeVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::HttpCode;
+
+    #[test]
+    fn deserialize() {
+        let from_int: HttpCode = serde_json::from_str("404").unwrap();
+        let from_str: HttpCode = serde_json::from_str("\"404\"").unwrap();
+        let from_status: HttpCode = serde_json::from_str("\"404 Not Found\"").unwrap();
+
+        assert_eq!(from_int, HttpCode::NotFound);
+        assert_eq!(from_str, HttpCode::NotFound);
+        assert_eq!(from_status, HttpCode::NotFound);
+        assert_eq!(
+            serde_json::from_str::<HttpCode>("599").unwrap(),
+            HttpCode::Unknown(599)
+        );
+        assert_eq!(
+            serde_json::from_str::<HttpCode>("null").unwrap(),
+            HttpCode::None
+        );
+        assert!(serde_json::from_str::<HttpCode>("\"Not Found\"").is_err());
+        assert!(serde_json::from_str::<HttpCode>("-1").is_err());
+        assert_eq!(
+            serde_json::from_str::<HttpCode>("\"404\\tnot found\"").unwrap(),
+            HttpCode::NotFound
+        );
+        assert!(serde_json::from_str::<HttpCode>("\"404 Bogus\"").is_err());
+        assert!(serde_json::from_str::<HttpCode>("\"404 Not Found Bogus\"").is_err());
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(serde_json::to_string(&HttpCode::NotFound).unwrap(), "404");
+        assert_eq!(serde_json::to_string(&HttpCode::None).unwrap(), "null");
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        for code in [HttpCode::NotFound, HttpCode::Unknown(599), HttpCode::None] {
+            let bytes = postcard::to_allocvec(&code).unwrap();
+
+            assert_eq!(postcard::from_bytes::<HttpCode>(&bytes).unwrap(), code);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::HttpCodeClass;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Log severities of the codes, ordered from the least to the most severe
+pub enum Severity {
+    /// Informational codes (1xx)
+    Info,
+
+    /// Successful codes (2xx)
+    Success,
+
+    /// Redirection codes (3xx)
+    Notice,
+
+    /// Client errors (4xx), missing and unknown codes
+    #[default]
+    Warning,
+
+    /// Server errors (5xx)
+    Error,
+}
+
+impl From<HttpCodeClass> for Severity {
+    fn from(value: HttpCodeClass) -> Self {
+        match value {
+            HttpCodeClass::Informational => Severity::Info,
+            HttpCodeClass::Successful => Severity::Success,
+            HttpCodeClass::Redirection => Severity::Notice,
+            HttpCodeClass::ClientError => Severity::Warning,
+            HttpCodeClass::ServerError => Severity::Error,
+            HttpCodeClass::None | HttpCodeClass::Unknown => Severity::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{HttpCode, Severity};
+
+    #[test]
+    fn code_to_severity() {
+        assert_eq!(HttpCode::Continue.severity(), Severity::Info);
+        assert_eq!(HttpCode::Created.severity(), Severity::Success);
+        assert_eq!(HttpCode::Found.severity(), Severity::Notice);
+        assert_eq!(HttpCode::NotFound.severity(), Severity::Warning);
+        assert_eq!(HttpCode::BadGateway.severity(), Severity::Error);
+        assert_eq!(HttpCode::Unknown(999).severity(), Severity::Warning);
+        assert_eq!(HttpCode::None.severity(), Severity::Warning);
+        assert!(Severity::Info < Severity::Error);
+    }
+}
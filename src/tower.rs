@@ -0,0 +1,129 @@
+// This file is part of http_codex <https://github.com/nevermille/http_codex>
+// Copyright (C) 2024 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::HttpCode;
+use ::http::Response;
+use ::tower_http::classify::{
+    ClassifiedResponse, ClassifyResponse, NeverClassifyEos, SharedClassifier,
+};
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, Debug, Default)]
+/// Response classifier for tower-http middlewares, like tracing or metrics
+///
+/// Server errors, including the 5xx codes without a variant, and retryable codes, like
+/// `429 Too Many Requests`, are failures.
+pub struct HttpCodeClassifier;
+
+impl HttpCodeClassifier {
+    /// Returns a classifier maker, as expected by the tower-http layers
+    pub fn make_classifier() -> SharedClassifier<Self> {
+        SharedClassifier::new(Self)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// The failures found by [`HttpCodeClassifier`]
+pub enum HttpCodeFailure {
+    /// The response has a failure code
+    Code(HttpCode),
+
+    /// The service failed with the given error
+    Error(String),
+}
+
+impl HttpCodeFailure {
+    /// Checks if the request may succeed when sent again
+    ///
+    /// Service errors are always considered retryable.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            HttpCodeFailure::Code(code) => code.is_retryable(),
+            HttpCodeFailure::Error(_) => true,
+        }
+    }
+}
+
+impl Display for HttpCodeFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpCodeFailure::Code(code) => write!(f, "status code: {code}"),
+            HttpCodeFailure::Error(error) => write!(f, "error: {error}"),
+        }
+    }
+}
+
+impl ClassifyResponse for HttpCodeClassifier {
+    type FailureClass = HttpCodeFailure;
+    type ClassifyEos = NeverClassifyEos<HttpCodeFailure>;
+
+    fn classify_response<B>(
+        self,
+        res: &Response<B>,
+    ) -> ClassifiedResponse<Self::FailureClass, Self::ClassifyEos> {
+        let code = HttpCode::from(res.status().as_u16());
+
+        if res.status().is_server_error() || code.is_retryable() {
+            ClassifiedResponse::Ready(Err(HttpCodeFailure::Code(code)))
+        } else {
+            ClassifiedResponse::Ready(Ok(()))
+        }
+    }
+
+    fn classify_error<E: Display + 'static>(self, error: &E) -> Self::FailureClass {
+        HttpCodeFailure::Error(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{HttpCode, HttpCodeClassifier, HttpCodeFailure};
+    use ::http::Response;
+    use ::tower_http::classify::{ClassifiedResponse, ClassifyResponse};
+
+    /// Classifies an empty response with the given status
+    fn classify(status: u16) -> Result<(), HttpCodeFailure> {
+        let mut response = Response::new(());
+        *response.status_mut() = status.try_into().unwrap();
+
+        match HttpCodeClassifier.classify_response(&response) {
+            ClassifiedResponse::Ready(result) => result,
+            ClassifiedResponse::RequiresEos(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn classify_response() {
+        assert_eq!(classify(200), Ok(()));
+        assert_eq!(classify(404), Ok(()));
+        assert_eq!(
+            classify(503),
+            Err(HttpCodeFailure::Code(HttpCode::ServiceUnavailable))
+        );
+        assert!(classify(503).unwrap_err().is_retryable());
+        assert_eq!(
+            classify(429),
+            Err(HttpCodeFailure::Code(HttpCode::TooManyRequests))
+        );
+        assert!(classify(429).unwrap_err().is_retryable());
+        assert!(!classify(500).unwrap_err().is_retryable());
+        assert_eq!(
+            classify(599),
+            Err(HttpCodeFailure::Code(HttpCode::Unknown(599)))
+        );
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    let _ = http_codex::http_code!(599);
+}